@@ -0,0 +1,66 @@
+//! Tolerance-based comparisons of floating point values.
+
+
+
+/// Types whose values can be compared up to some tolerance epsilon,
+/// which is needed for floating point results that are subject to rounding errors.
+pub trait ApproxEq {
+    /// Determines whether the two values differ by at most epsilon 
+    /// (entry-wise for compound values).
+    fn approx_eq(self: &Self, other: &Self, epsilon: f64) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(self: &Self, other: &Self, epsilon: f64) -> bool {
+        ((*self as f64) - (*other as f64)).abs() <= epsilon
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(self: &Self, other: &Self, epsilon: f64) -> bool {
+        (self - other).abs() <= epsilon
+    }
+}
+
+/// Vectors are approximately equal if they have the same length 
+/// and all their entries are approximately equal.
+impl ApproxEq for Vec<f32> {
+    fn approx_eq(self: &Self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(x, y)| x.approx_eq(y, epsilon))
+    }
+}
+
+
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::approx::*;
+
+    #[test]
+    fn approx_eq_float_test() {
+        println!("Comparing floating point numbers up to a tolerance.");
+
+        assert!((0.1_f32 + 0.2_f32).approx_eq(&0.3, 1e-6));
+        assert!(!(426.0_f32).approx_eq(&426.1, 1e-6));
+        assert!((0.1_f64 + 0.2_f64).approx_eq(&0.3, 1e-12));
+        assert!(!(0.1_f64 + 0.2_f64).approx_eq(&0.3, 1e-20));
+    }
+
+    #[test]
+    fn approx_eq_vector_test() {
+        println!("Comparing vectors up to a tolerance.");
+
+        assert!(vec![1.0, 2.0, 3.0].approx_eq(&vec![1.0, 2.0000001, 3.0], 1e-5));
+        assert!(!vec![1.0, 2.0, 3.0].approx_eq(&vec![1.0, 2.1, 3.0], 1e-5));
+
+        println!("Asserting that vectors of different lengths are not approximately equal.");
+
+        assert!(!vec![1.0, 2.0].approx_eq(&vec![1.0, 2.0, 0.0], 1e-5));
+        assert!(Vec::<f32>::new().approx_eq(&vec![], 1e-5));
+    }
+}
\ No newline at end of file
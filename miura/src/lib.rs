@@ -2,6 +2,9 @@
 pub mod poly;
 pub mod vec_helper;
 pub mod permutation;
-mod series; // unfinished and thus not made accessible
+pub mod series;
 pub mod sorting;
-pub mod matrix;
\ No newline at end of file
+pub mod matrix;
+pub mod number_theory;
+pub mod rational;
+pub mod approx;
\ No newline at end of file
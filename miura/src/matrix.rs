@@ -4,7 +4,14 @@
 
 
 use crate::vec_helper::scale_vector;
-use crate::vec_helper::is_zero_vector;
+use crate::vec_helper::scale_vector_into;
+use crate::vec_helper::is_zero_slice;
+use crate::vec_helper::dot_product;
+use crate::poly::{IntPoly, Modulus}; // for characteristic polynomials
+use crate::rational::Rational; // for solving linear systems exactly
+use crate::number_theory::{is_prime, mod_inverse, reduce_mod_wide}; // for inverting matrices over prime fields
+use crate::approx::ApproxEq; // for comparing matrices up to rounding errors
+use crate::permutation::Permutation; // for the row permutation of LU decompositions
 
 /// A struct describing a matrix of real numbers
 /// with double floating point precision.
@@ -55,6 +62,28 @@ impl Matrix {
         result
     }
 
+    /// Returns the entry in row i and column j of the matrix,
+    /// or None if the indices are out of range.
+    pub fn get(self: &Self, i: usize, j: usize) -> Option<f32> {
+        self.rows.get(i)?.get(j).copied()
+    }
+
+    /// Returns the i-th row of the matrix as a vector,
+    /// or None if the index is out of range.
+    pub fn get_row(self: &Self, i: usize) -> Option<Vec<f32>> {
+        self.rows.get(i).map(|row| row.to_vec())
+    }
+
+    /// Returns the j-th column of the matrix as a vector,
+    /// or None if the index is out of range.
+    pub fn get_column(self: &Self, j: usize) -> Option<Vec<f32>> {
+        if self.rows.is_empty() || j >= self.num_columns() {
+            return None;
+        }
+
+        Some(self.column(j))
+    }
+
     /// Returns the number of rows of this matrix.
     pub fn num_rows(self: &Self) -> usize {
         self.rows.len()
@@ -71,6 +100,129 @@ impl Matrix {
 
     
 
+    // ---------------- reshaping operations -------------------
+
+
+
+    /// Returns a copy of the matrix with row i removed.
+    ///
+    /// If there is no row i or it is the only row of the matrix, an error variant is returned.
+    pub fn delete_row(self: &Self, i: usize) -> Result<Matrix, MatrixError> {
+        // a matrix has at least one row
+        if i >= self.num_rows() || self.num_rows() == 1 {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        let mut rows = self.rows.clone();
+        rows.remove(i);
+
+        Ok(Matrix {
+            rows
+        })
+    }
+
+    /// Returns a copy of the matrix with column j removed.
+    ///
+    /// If there is no column j or it is the only column of the matrix, an error variant is returned.
+    pub fn delete_column(self: &Self, j: usize) -> Result<Matrix, MatrixError> {
+        // like reshape, no matrix with empty rows is produced
+        if j >= self.num_columns() || self.num_columns() == 1 {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        let mut rows = self.rows.clone();
+        for row in rows.iter_mut() {
+            row.remove(j);
+        }
+
+        Ok(Matrix {
+            rows
+        })
+    }
+
+    /// Returns the transpose of the matrix,
+    /// i.e. the matrix whose i-th row is the i-th column of this matrix.
+    pub fn transpose(self: &Self) -> Matrix {
+        Matrix {
+            rows: (0..self.num_columns()).map(|j| self.column(j)).collect()
+        }
+    }
+
+    /// Returns a matrix with the passed number of rows and columns
+    /// that contains the entries of this matrix in row-major order,
+    /// i.e. the entries are read row by row and written row by row.
+    ///
+    /// If the number of entries of the new shape does not match the one of this matrix
+    /// or one of the new dimensions is 0, an error variant is returned.
+    pub fn reshape(self: &Self, rows: usize, cols: usize) -> Result<Matrix, MatrixError> {
+        // a matrix has at least one row, and rows cannot be cut into chunks of length 0
+        if rows == 0 || cols == 0 || rows * cols != self.num_rows() * self.num_columns() {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        // concatenate all rows into one vector and cut it into chunks of the new row length
+        let entries = self.rows.concat();
+
+        Matrix::new(
+            entries.chunks(cols).map(|chunk| chunk.to_vec()).collect()
+        )
+    }
+
+
+
+    /// Returns the matrix [self | other] obtained by appending the columns of the other matrix
+    /// to the right of the columns of this matrix.
+    ///
+    /// If the two matrices do not have the same number of rows, an error variant is returned.
+    pub fn augment(self: &Self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.num_rows() != other.num_rows() {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        Matrix::new(
+            self.rows.iter().zip(other.rows.iter())
+                .map(|(left, right)| [left.as_slice(), right.as_slice()].concat())
+                .collect()
+        )
+    }
+
+    /// Returns the matrix obtained by appending the rows of the other matrix
+    /// below the rows of this matrix.
+    ///
+    /// If the two matrices do not have the same number of columns, an error variant is returned.
+    pub fn stack(self: &Self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.num_columns() != other.num_columns() {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        Matrix::new(
+            [self.rows.as_slice(), other.rows.as_slice()].concat()
+        )
+    }
+
+    /// Returns the matrix obtained by deleting row i and column j of this matrix.
+    /// Its determinant is the (i, j)-minor of this matrix.
+    ///
+    /// If row i or column j cannot be deleted (see delete_row and delete_column), an error variant is returned.
+    pub fn minor(self: &Self, i: usize, j: usize) -> Result<Matrix, MatrixError> {
+        self.delete_row(i)?.delete_column(j)
+    }
+
+    /// Returns the submatrix consisting of the entries 
+    /// in the passed rows and columns (in the passed order).
+    pub fn submatrix(self: &Self, rows: &[usize], columns: &[usize]) -> Matrix {
+        Matrix {
+            rows: rows.iter().map(|&i| columns.iter().map(|&j| self.rows[i][j]).collect()).collect()
+        }
+    }
+
+
+
+    // ---------------- end of reshaping operations -------------------
+
+
+
+
     // ---------------- row operations -------------------
 
 
@@ -78,7 +230,7 @@ impl Matrix {
     /// Scales row i of the matrix with the scale factor c.
     /// Note that this operation is rank-preserving if and only if c != 0.
     pub fn scale_row(self: &mut Self, i: usize, c: f32) {
-        self.rows[i] = scale_vector(&(self.rows[i]), c);
+        scale_vector_into(&mut self.rows[i], c);
     }
 
     /// Switches the rows i and j of the matrix.
@@ -110,6 +262,86 @@ impl Matrix {
 
 
 
+    // ---------------- arithmetic operations -------------------
+
+
+
+    /// Returns the matrix obtained by multiplying every entry of this matrix with the scale factor c.
+    pub fn scale(self: &Self, c: f32) -> Matrix {
+        Matrix {
+            rows: self.rows.iter().map(|row| scale_vector(row, c)).collect()
+        }
+    }
+
+    /// Takes the matrix to the power determined by the passed exponent,
+    /// where the 0-th power is the identity matrix.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn pow(self: &Self, exponent: usize) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+
+        let mut result = identity_matrix(self.num_rows());
+        for _ in 0..exponent {
+            result = multiply(&result, self)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the trace of the matrix, i.e. the sum of its diagonal entries.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn trace(self: &Self) -> Result<f32, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+
+        Ok((0..self.num_rows()).map(|i| self.entry(i, i)).sum())
+    }
+
+    /// Returns the vector obtained by multiplying the matrix with the passed (column) vector v.
+    ///
+    /// If the length of v does not match the number of columns of the matrix, an error variant is returned.
+    pub fn apply_to_vector(self: &Self, v: &[f32]) -> Result<Vec<f32>, MatrixError> {
+        if v.len() != self.num_columns() {
+            return Err(MatrixError::DimensionMismatchError);
+        }
+
+        Ok(self.rows.iter().map(|row| row.iter().zip(v.iter()).map(|(x, y)| x * y).sum()).collect())
+    }
+
+    /// Returns the Gram matrix A^T * A of the matrix A, 
+    /// whose entry (i, j) is the dot product of the i-th and the j-th column of A.
+    ///
+    /// The entries are computed directly from the columns without materializing the transpose.
+    /// The result is square and symmetric, so only the entries on and above the diagonal are computed.
+    pub fn gram(self: &Self) -> Matrix {
+        let n = self.num_columns();
+        let columns = (0..n).map(|j| self.column(j)).collect::<Vec<Vec<f32>>>();
+
+        let mut rows = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in i..n {
+                // all columns have the same length, so the dot product is defined
+                let entry = dot_product(&columns[i], &columns[j]).unwrap();
+                rows[i][j] = entry;
+                rows[j][i] = entry;
+            }
+        }
+
+        Matrix {
+            rows
+        }
+    }
+
+
+
+    // ---------------- end of arithmetic operations -------------------
+
+
+
     /// Transforms the matrix to its upper triangular form.
     ///
     /// This is done by transforming each row into a pivot row.
@@ -119,31 +351,50 @@ impl Matrix {
     ///
     /// A matrix is an upper triangular matrix if its rows are ordered by their pivot positions, ascendingly.
     pub fn to_upper_triangular(self: &mut Self) {
+        self.to_upper_triangular_with_pivots();
+    }
+
+    /*
+    * Performs the transformation of to_upper_triangular
+    * and returns the pivot positions in the order of the rows, 
+    * as they are found during the elimination (so they do not have to be re-derived from float entries).
+    */
+    fn to_upper_triangular_with_pivots(self: &mut Self) -> Vec<usize> {
+        let mut pivot_positions = vec![];
         let mut current_pivot_position = 0;
 
-        'pivot_row_creation: for current_row in 0..(self.num_rows()) {
+        for current_row in 0..(self.num_rows()) {
             /*
-            * Search for pivot position in current row.
-            * If reached end of row : 
-            * no pivot position exists in current row, continue with next row
-            * (start looking for pivot column from the main diagonal).
+            * Search for the next pivot position, starting from the column right of the last pivot:
+            * the leftmost column that has a non-zero entry in the current row or some row below it.
             */
-            while self.next_row_without_zero_at_beginning_from(current_pivot_position, current_row) == None { 
-                current_pivot_position += 1;
-                // if reached end of row: no pivot position exists in this row.
-                if current_pivot_position==self.num_columns() {
-                    /*
-                    * Search for pivot position in next row 
-                    * should continue from the left end of the row.
-                    */
-                    current_pivot_position = 0;
-                    continue 'pivot_row_creation;
+            let mut pivot_row = None;
+            while current_pivot_position < self.num_columns() {
+                pivot_row = self.next_row_without_zero_at_beginning_from(current_pivot_position, current_row);
+                if pivot_row.is_some() {
+                    break;
                 }
+                current_pivot_position += 1;
             }
+
+            /*
+            * If reached end of row without finding a pivot position:
+            * the current row and all rows below it are zero rows,
+            * so the matrix is already in upper triangular form.
+            * This in particular covers the zero matrix.
+            */
+            let pivot_row = match pivot_row {
+                Some(k) => k,
+                None => return pivot_positions
+            };
+            pivot_positions.push(current_pivot_position);
+
+            // move the row containing the pivot element to the current row
+            self.switch_rows(current_row, pivot_row);
                 
             // normalize the row
             self.scale_row(
-                current_row, // row index (= column index)
+                current_row,
                 1.0 / self.entry(current_row, current_pivot_position)
             );
 
@@ -157,401 +408,2532 @@ impl Matrix {
                     );
                 }
             }
-        }
-    }
 
+            // pivot of the next row has to be strictly right of the current one
+            current_pivot_position += 1;
+        }
 
+        pivot_positions
+    }
 
-    // -------------- helper functions for computing upper triangular matrix ------------
 
 
+    /// Transforms the matrix to its upper triangular form like to_upper_triangular does
+    /// and returns the matrix E of the performed row operations, 
+    /// i.e. E * original == reduced.
+    ///
+    /// This is done by performing the transformation on the augmented matrix [A | I],
+    /// which yields [E * A | E] since every row operation is a multiplication from the left.
+    pub fn to_reduced_with_record(self: &mut Self) -> Matrix {
+        let n = self.num_columns();
 
-    /// Determines whether the i-th row of the matrix is a zero row.
-    pub fn is_zero_row(self: &Self, i: usize) -> bool {
-        is_zero_vector(self.row(i))
-    }
+        // the identity has as many rows as the matrix, so augmenting cannot fail
+        let mut augmented = self.augment(&identity_matrix(self.num_rows())).unwrap();
+        augmented.to_upper_triangular();
 
-    /// Determines whether the j-th column of the matrix is a zero column.
-    pub fn is_zero_column(self: &Self, j: usize) -> bool {
-        is_zero_vector(self.column(j))
-    }
+        /*
+        * Pivots are searched in the columns of A first,
+        * so the left block is the upper triangular form of A.
+        */
+        self.rows = augmented.rows.iter().map(|row| row[..n].to_vec()).collect();
 
-    /// Beginning search from row i, 
-    /// this method returns the index of the first row of the matrix having no 0 at position (column) j.
-    /// If no such row exists, the None variant is returned.
-    pub fn next_row_without_zero_at_beginning_from(self: &Self, j: usize, i: usize) -> Option<usize> {
-        for k in i..self.num_rows() {
-            if self.rows[k][j] != 0.0 {
-                return Some(k);
-            }
+        Matrix {
+            rows: augmented.rows.iter().map(|row| row[n..].to_vec()).collect()
         }
-        None
     }
 
-    /// Beginning search from row i,
-    /// this method returns the index of the first row of the matrix that is not a zero row.
-    /// If no such row exists, the None variant is returned.
-    pub fn next_non_zero_row_beginning_from(self: &Self, i: usize) -> Option<usize> {
-        for k in i..self.num_rows() {
-            if !self.is_zero_row(k) {
-                return Some(k);
+    /// Determines whether the matrix is in reduced row echelon form,
+    /// which is the form produced by to_upper_triangular.
+    ///
+    /// This is the case if 
+    /// (i)   every pivot element (first non-zero entry of a row) is 1,
+    /// (ii)  every pivot element is the only non-zero entry in its column,
+    /// (iii) the pivot positions move strictly to the right from each row to the next and
+    /// (iv)  all zero rows are at the bottom of the matrix.
+    ///
+    /// Entries with an absolute value of at most epsilon are treated as zero,
+    /// and pivot elements with a distance of at most epsilon to 1 are treated as 1.
+    pub fn is_reduced_row_echelon(self: &Self, epsilon: f32) -> bool {
+        let is_zero = |x: f32| x.abs() <= epsilon;
+
+        // pivot position of the last non-zero row, None if no non-zero row was seen yet
+        let mut last_pivot_position: Option<usize> = None;
+        let mut seen_zero_row = false;
+
+        for i in 0..self.num_rows() {
+            match self.rows[i].iter().position(|&x| !is_zero(x)) {
+                // zero row: all following rows have to be zero rows as well
+                None => seen_zero_row = true,
+                Some(j) => {
+                    // (iv) non-zero row below a zero row
+                    if seen_zero_row {
+                        return false;
+                    }
+
+                    // (iii) pivot not strictly right of the previous one
+                    if let Some(last) = last_pivot_position {
+                        if j <= last {
+                            return false;
+                        }
+                    }
+
+                    // (i) pivot element is not 1
+                    if (self.entry(i, j) - 1.0).abs() > epsilon {
+                        return false;
+                    }
+
+                    // (ii) other non-zero entries in the pivot column
+                    for k in 0..self.num_rows() {
+                        if k != i && !is_zero(self.entry(k, j)) {
+                            return false;
+                        }
+                    }
+
+                    last_pivot_position = Some(j);
+                }
             }
         }
-        None
+
+        true
     }
 
+    /// Returns the indices of the pivot columns of the matrix,
+    /// i.e. of the columns that contain a pivot element after transformation to upper triangular form.
+    ///
+    /// When the matrix is the coefficient matrix of a linear system,
+    /// the pivot columns correspond to the basic variables
+    /// while all other columns correspond to free variables.
+    ///
+    /// The matrix itself is not changed, the transformation is performed on a copy.
+    pub fn pivot_columns(self: &Self) -> Vec<usize> {
+        // the pivot positions are recorded during the transformation, zero rows do not contain a pivot element
+        let mut reduced = self.clone();
+        reduced.to_upper_triangular_with_pivots()
+    }
 
+    /// Interprets the matrix as the augmented matrix [A | b] of a linear system A * x = b
+    /// with the passed number of unknowns 
+    /// (so the matrix is expected to have num_unknowns + 1 columns)
+    /// and describes the set of its solutions.
+    ///
+    /// The matrix is transformed into upper triangular form first (on a copy).
+    /// The system has no solution if the last column is a pivot column,
+    /// i.e. the reduced system contains an equation 0 = 1.
+    /// Otherwise, every column without a pivot belongs to a free variable,
+    /// and the system has infinitely many solutions if there is at least one free variable.
+    ///
+    /// Panics if the matrix does not have num_unknowns + 1 columns.
+    pub fn describe_solution(self: &Self, num_unknowns: usize) -> SolutionSet {
+        if self.num_columns() != num_unknowns + 1 {
+            panic!(
+                "describe_solution: expected {} columns for {} unknowns, but the matrix has {} columns",
+                num_unknowns + 1, num_unknowns, self.num_columns()
+            );
+        }
 
-    // -------------- end of helper functions for computing upper triangular matrix ------------
+        let mut reduced = self.clone();
+        let pivot_columns = reduced.to_upper_triangular_with_pivots();
 
+        // pivot in the column of b: inconsistent system
+        if pivot_columns.contains(&num_unknowns) {
+            return SolutionSet::None;
+        }
 
+        /*
+        * Particular solution: set all free variables to 0.
+        * Then, the basic variable of the k-th pivot row equals the k-th entry of b in the reduced system.
+        */
+        let mut particular = vec![0.0; num_unknowns];
+        for (k, &j) in pivot_columns.iter().enumerate() {
+            particular[j] = reduced.entry(k, num_unknowns);
+        }
 
-    // -------------- debug only functions ---------------------------------------
+        let free_columns = (0..num_unknowns).filter(|j| !pivot_columns.contains(j)).collect::<Vec<usize>>();
 
+        if free_columns.is_empty() {
+            return SolutionSet::Unique(particular);
+        }
 
-    /// Prints the matrix to the console for debug purposes.
-    pub fn display_matrix(a: &Matrix) {
-        for i in 0..a.num_rows() {
-            for j in 0..a.num_columns() {
-                print!("{} ", a.entry(i, j));
+        /*
+        * Basis of the solution space of the homogeneous system: 
+        * for every free variable, set it to 1 and all other free variables to 0.
+        * The basic variable of the k-th pivot row then is the negated entry of that row in the free column.
+        */
+        let mut basis = Vec::<Vec<f32>>::new();
+        for &f in free_columns.iter() {
+            let mut basis_vector = vec![0.0; num_unknowns];
+            basis_vector[f] = 1.0;
+            for (k, &j) in pivot_columns.iter().enumerate() {
+                basis_vector[j] = - reduced.entry(k, f);
             }
-            println!("\n");
+            basis.push(basis_vector);
+        }
+
+        SolutionSet::Infinite {
+            particular,
+            basis
         }
-        println!("------------------------");
     }
-}
 
+    /// Computes a basis of the null space (kernel) of the matrix,
+    /// i.e. of the solution space of the homogeneous system A * x = 0.
+    ///
+    /// If the kernel is trivial (e.g. for an invertible matrix), the returned list is empty.
+    pub fn null_space(self: &Self) -> Vec<Vec<f32>> {
+        let zero_column = Matrix {
+            rows: vec![vec![0.0]; self.num_rows()]
+        };
+
+        // augmenting cannot fail since the zero column has as many rows as the matrix
+        match self.augment(&zero_column).unwrap().describe_solution(self.num_columns()) {
+            SolutionSet::Infinite { basis, .. } => basis,
+            _ => vec![]
+        }
+    }
 
-/// Type modelling all different kinds of errors
-/// that can occur when working with real matrices. 
-#[derive(PartialEq, Debug, Clone)]
-pub enum MatrixError{
-    NonUniformRowLengthError
-}
+    /// Computes an orthonormal basis of the null space (kernel) of the matrix
+    /// by orthonormalizing the basis returned by null_space with the Gram-Schmidt process.
+    ///
+    /// If the kernel is trivial (e.g. for an invertible matrix), the returned list is empty.
+    pub fn orthonormal_null_space(self: &Self) -> Vec<Vec<f32>> {
+        gram_schmidt(&self.null_space())
+    }
 
+    /// Computes, for every k from 1 to n, the sum of all k x k principal minors of the n x n matrix,
+    /// i.e. of the determinants of all submatrices with the same k rows and columns.
+    ///
+    /// These sums E_1, ..., E_n determine the characteristic polynomial
+    /// X^n - E_1 * X^(n-1) + E_2 * X^(n-2) - ... + (-1)^n * E_n,
+    /// e.g. E_1 is the trace and E_n is the determinant.
+    /// Note that all 2^n subsets of rows are considered, so this is only feasible for small n.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn principal_minor_sums(self: &Self) -> Result<Vec<f32>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
 
+        let n = self.num_rows();
+        let mut sums = vec![0.0; n];
 
+        // every non-zero bitmask encodes a non-empty subset of {0, ..., n-1}
+        for mask in 1..(1usize << n) {
+            let indices = (0..n).filter(|&i| mask & (1 << i) != 0).collect::<Vec<usize>>();
+            sums[indices.len() - 1] += self.submatrix(&indices, &indices).determinant()?;
+        }
 
+        Ok(sums)
+    }
 
+    /// Determines whether the matrix is a square matrix,
+    /// i.e. has as many rows as columns.
+    pub fn is_square(self: &Self) -> bool {
+        self.num_rows() == self.num_columns()
+    }
 
+    /// Computes the inverse of the matrix via Gauss-Jordan elimination,
+    /// i.e. by transforming the augmented matrix [A | I] into upper triangular form [I | A^(-1)].
+    ///
+    /// If the matrix is not square or singular (not invertible), an error variant is returned.
+    pub fn inverse(self: &Self) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
 
+        let n = self.num_rows();
+        let mut augmented = self.augment(&identity_matrix(n))?;
+        let pivots = augmented.to_upper_triangular_with_pivots();
 
-#[cfg(test)]
-mod tests {
-    use crate::matrix::*;
+        /*
+        * The matrix is invertible iff every column of the left block is a pivot column.
+        * (Comparing the diagonal of the reduced form with 1.0 is not an option:
+        * normalized pivots like 41 * (1/41) need not be exactly 1.0 in floating point arithmetic.)
+        */
+        if pivots.iter().filter(|&&j| j < n).count() < n {
+            return Err(MatrixError::SingularMatrixError);
+        }
 
-    #[test]
-    fn matrix_construction_test() {
-        println!("Creating test 3x3 matrix.");
+        Ok(
+            Matrix {
+                rows: augmented.rows.iter().map(|row| row[n..].to_vec()).collect()
+            }
+        )
+    }
 
-        let test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 2.0, 3.0],
-                vec![4.0, 5.0, 6.0],
-                vec![7.0, 8.0, 426.0]
-            ]
-        );
+    /// Returns the Frobenius norm of the matrix, 
+    /// i.e. the square root of the sum of the squares of all entries.
+    pub fn frobenius_norm(self: &Self) -> f32 {
+        self.rows.iter().flatten().map(|x| x * x).sum::<f32>().sqrt()
+    }
 
-        assert!(test_matrix.is_ok());
+    /// Estimates the condition number ||A|| * ||A^(-1)|| of the matrix A 
+    /// with respect to the Frobenius norm.
+    ///
+    /// A large condition number indicates that solving linear systems with this matrix 
+    /// is sensitive to rounding errors, so the results of solve can be unreliable.
+    /// Note that with respect to the Frobenius norm, the condition number of an n x n matrix is at least n
+    /// (which is attained by the identity).
+    ///
+    /// If the matrix is not square or singular, an error variant is returned.
+    pub fn condition_number(self: &Self) -> Result<f32, MatrixError> {
+        Ok(self.frobenius_norm() * self.inverse()?.frobenius_norm())
+    }
 
-        println!("Attempting to create a matrix with rows of non-uniform length.");
+    /// Computes the LU decomposition of the matrix A with partial pivoting,
+    /// i.e. a lower triangular matrix L with unit diagonal, an upper triangular matrix U
+    /// and a permutation sigma such that P * A = L * U, 
+    /// where P is the permutation matrix whose i-th row is the sigma(i)-th unit vector
+    /// (so the i-th row of P * A is the sigma(i)-th row of A).
+    ///
+    /// In every elimination step, the row with the largest absolute value in the current column
+    /// (on or below the diagonal) is chosen as pivot row, which keeps the multipliers in L bounded by 1.
+    /// For singular matrices, a column without non-zero pivot candidate is skipped,
+    /// so the decomposition still exists, but U contains a zero on the diagonal.
+    /// Pivot candidates are considered zero if their absolute value is negligible 
+    /// compared to the largest absolute value of an entry of the matrix (relative tolerance 1e-6).
+    /// The entries of U below the diagonal are set to exactly 0.0 instead of being computed,
+    /// so U is upper triangular despite rounding errors.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn lu_decompose(self: &Self) -> Result<(Matrix, Matrix, Permutation), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
 
-        let errornous_matrix = Matrix::new(
-            vec![
-                vec![1.0, 2.0],
-                vec![426.0, 426.0, 426.0]
-            ]
-        );
+        let n = self.num_rows();
+        let mut u = self.rows.clone();
+        let mut l = vec![vec![0.0; n]; n];
+        let mut images = (1..(n+1)).collect::<Vec<usize>>();
+
+        // pivots that vanish up to rounding errors (relative to the size of the entries) are treated as zero
+        let tolerance = 1e-6 * self.rows.iter().flatten().fold(1.0, |acc: f32, x| acc.max(x.abs()));
+
+        for k in 0..n {
+            // partial pivoting: choose the candidate with the largest absolute value
+            let pivot_row = (k..n).max_by(|&i, &j| u[i][k].abs().total_cmp(&u[j][k].abs())).unwrap();
+            if pivot_row != k {
+                u.swap(k, pivot_row);
+                images.swap(k, pivot_row);
+                // the multipliers computed so far belong to the swapped rows (and pivot_row > k)
+                let (upper, lower) = l.split_at_mut(pivot_row);
+                upper[k][..k].swap_with_slice(&mut lower[0][..k]);
+            }
 
-        assert_eq!(errornous_matrix, Err(MatrixError::NonUniformRowLengthError));
-    }
+            // zero column below the diagonal: nothing to eliminate, remove the rounding errors
+            if u[k][k].abs() <= tolerance {
+                for row in u[(k+1)..].iter_mut() {
+                    row[k] = 0.0;
+                }
+                continue;
+            }
 
+            let pivot = u[k].clone();
+            for i in (k+1)..n {
+                let factor = u[i][k] / pivot[k];
+                l[i][k] = factor;
 
+                // the factor is chosen such that the entry below the pivot vanishes
+                u[i][k] = 0.0;
+                for (u_ij, &u_kj) in u[i][(k+1)..].iter_mut().zip(pivot[(k+1)..].iter()) {
+                    *u_ij -= factor * u_kj;
+                }
+            }
+        }
 
-    #[test]
-    fn rows_and_columns_test() {
-        println!("Creating test 3x3 matrix.");
+        for (k, row) in l.iter_mut().enumerate() {
+            row[k] = 1.0;
+        }
 
-        let test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 2.0, 3.0],
-                vec![4.0, 5.0, 6.0],
-                vec![7.0, 8.0, 426.0]
-            ]
-        ).unwrap();
+        // images is a permutation of (1, ..., n) since only swaps were applied
+        Ok((Matrix { rows: l }, Matrix { rows: u }, Permutation::new(images).unwrap()))
+    }
 
-        println!("Asserting that 2nd row is extracted correctly.");
+    /// Computes the determinant of the matrix via Gaussian elimination.
+    ///
+    /// The matrix is transformed into an upper triangular matrix 
+    /// using only row switches (each flipping the sign of the determinant)
+    /// and additions of scalar multiples of rows (not changing it).
+    /// The determinant then is the product of the diagonal entries.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn determinant(self: &Self) -> Result<f32, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
 
-        assert_eq!(test_matrix.row(1), vec![4.0, 5.0, 6.0]);
+        let mut a = self.clone();
+        let n = a.num_rows();
+        let mut result = 1.0;
 
-        println!("Asserting that 3rd column is extracted correctly.");
+        for j in 0..n {
+            /*
+            * Partial pivoting: use the row with the entry of largest absolute value 
+            * in column j as pivot row, this keeps rounding errors small.
+            */
+            let mut pivot_row = j;
+            for k in (j+1)..n {
+                if a.entry(k, j).abs() > a.entry(pivot_row, j).abs() {
+                    pivot_row = k;
+                }
+            }
 
-        assert_eq!(test_matrix.column(2), vec![3.0, 6.0, 426.0]);
-    }
+            // zero column below the diagonal: matrix is singular
+            if a.entry(pivot_row, j) == 0.0 {
+                return Ok(0.0);
+            }
 
-    #[test]
+            if pivot_row != j {
+                a.switch_rows(pivot_row, j);
+                result = -result;
+            }
+
+            // eliminate all entries below the pivot
+            for k in (j+1)..n {
+                let factor = - a.entry(k, j) / a.entry(j, j);
+                a.add_scalar_multiple(k, factor, j);
+            }
+
+            result *= a.entry(j, j);
+        }
+
+        Ok(result)
+    }
+
+    /// Computes the determinant of the matrix via recursive Laplace (cofactor) expansion
+    /// along the first row.
+    ///
+    /// Since no divisions are performed, the result is exact for matrices with (small) integer entries.
+    /// However, the running time is in O(n!), so this method is only suited for small n.
+    /// For larger matrices, use the elimination-based determinant method.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn determinant_cofactor(self: &Self) -> Result<f32, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+
+        // recursion anchor: determinant of a 1x1 matrix is its only entry
+        if self.num_rows() == 1 {
+            return Ok(self.entry(0, 0));
+        }
+
+        let mut result = 0.0;
+        for j in 0..self.num_columns() {
+            // the sign of the cofactor alternates along the row
+            let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+
+            // minor of a square matrix with at least two rows exists and is square again, so this never fails
+            result += sign * self.entry(0, j) * self.minor(0, j)?.determinant_cofactor()?;
+        }
+
+        Ok(result)
+    }
+
+    /// Computes the permanent of the matrix, 
+    /// i.e. the sum of the products a_(1, sigma(1)) * ... * a_(n, sigma(n)) over all permutations sigma
+    /// (like the Leibniz formula for the determinant, but without signs).
+    /// For a 0-1 matrix, this counts the perfect matchings of the corresponding bipartite graph.
+    ///
+    /// Uses Ryser's formula perm(A) = (-1)^n * sum over all column subsets S of (-1)^|S| * prod_i (sum_(j in S) a_ij),
+    /// which needs O(2^n * n^2) operations instead of O(n! * n) for the naive expansion.
+    ///
+    /// If the matrix is not square, an error variant is returned.
+    pub fn permanent(self: &Self) -> Result<f32, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+
+        let n = self.num_rows();
+        let mut result = 0.0;
+
+        // every bitmask encodes a subset S of the columns {0, ..., n-1}, the empty subset contributes 0
+        for mask in 1..(1usize << n) {
+            let product: f32 = self.rows.iter()
+                .map(|row| (0..n).filter(|&j| mask & (1 << j) != 0).map(|j| row[j]).sum::<f32>())
+                .product();
+
+            let sign = if (n - mask.count_ones() as usize).is_multiple_of(2) { 1.0 } else { -1.0 };
+            result += sign * product;
+        }
+
+        Ok(result)
+    }
+
+
+
+    /// Computes the characteristic polynomial det(X * I - A) of the matrix A 
+    /// using the Faddeev-LeVerrier algorithm.
+    ///
+    /// The characteristic polynomial is returned as an integer polynomial,
+    /// so the matrix is required to have integer entries.
+    /// If it is not square or has non-integer entries, an error variant is returned.
+    pub fn characteristic_polynomial(self: &Self) -> Result<IntPoly, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+        if self.rows.iter().flatten().any(|x| x.fract() != 0.0) {
+            return Err(MatrixError::NonIntegerEntryError);
+        }
+
+        let n = self.num_rows();
+
+        /*
+        * The Faddeev-LeVerrier algorithm computes the coefficients c_n, c_(n-1), ..., c_0
+        * of the characteristic polynomial together with auxiliary matrices M_0, M_1, ..., M_n via
+        * M_0 = 0, c_n = 1,
+        * M_k = A * M_(k-1) + c_(n-k+1) * I,
+        * c_(n-k) = -1/k * trace(A * M_k).
+        */
+        let mut coeffs = vec![0.0; n + 1];
+        coeffs[n] = 1.0;
+        let mut m = Matrix::new(vec![vec![0.0; n]; n])?;
+
+        for k in 1..(n+1) {
+            m = add(&multiply(self, &m)?, &identity_matrix(n).scale(coeffs[n - k + 1]))?;
+            coeffs[n - k] = - multiply(self, &m)?.trace()? / (k as f32);
+        }
+
+        // for integer matrices, all coefficients are integers up to rounding errors
+        Ok(IntPoly::new_unchecked(
+            &mut coeffs.iter().map(|c| c.round() as i32).collect(),
+            Modulus::None
+        ))
+    }
+
+    /// Checks the Cayley-Hamilton theorem for the matrix A numerically,
+    /// i.e. whether p(A) is the zero matrix (up to the passed tolerance epsilon in every entry)
+    /// for the characteristic polynomial p of A.
+    ///
+    /// If the matrix is not square or has non-integer entries, an error variant is returned.
+    pub fn satisfies_characteristic_polynomial(self: &Self, epsilon: f32) -> Result<bool, MatrixError> {
+        let p = self.characteristic_polynomial()?;
+        let p_of_a = p.evaluate_at_matrix(self)?;
+
+        Ok(p_of_a.approx_eq(&self.scale(0.0), epsilon as f64))
+    }
+
+
+
+    // -------------- helper functions for computing upper triangular matrix ------------
+
+
+
+    /// Determines whether the i-th row of the matrix is a zero row.
+    pub fn is_zero_row(self: &Self, i: usize) -> bool {
+        // check the stored row directly instead of copying it
+        is_zero_slice(&self.rows[i])
+    }
+
+    /// Determines whether the j-th column of the matrix is a zero column.
+    pub fn is_zero_column(self: &Self, j: usize) -> bool {
+        // scan the j-th entries of the stored rows, stopping at the first non-zero one
+        self.rows.iter().all(|row| row[j] == 0.0)
+    }
+
+    /// Beginning search from row i, 
+    /// this method returns the index of the first row of the matrix having no 0 at position (column) j.
+    /// If no such row exists, the None variant is returned.
+    pub fn next_row_without_zero_at_beginning_from(self: &Self, j: usize, i: usize) -> Option<usize> {
+        for k in i..self.num_rows() {
+            if self.rows[k][j] != 0.0 {
+                return Some(k);
+            }
+        }
+        None
+    }
+
+    /// Beginning search from row i,
+    /// this method returns the index of the first row of the matrix that is not a zero row.
+    /// If no such row exists, the None variant is returned.
+    pub fn next_non_zero_row_beginning_from(self: &Self, i: usize) -> Option<usize> {
+        for k in i..self.num_rows() {
+            if !self.is_zero_row(k) {
+                return Some(k);
+            }
+        }
+        None
+    }
+
+
+
+    // -------------- end of helper functions for computing upper triangular matrix ------------
+
+
+
+    // -------------- debug only functions ---------------------------------------
+
+
+    /// Prints the matrix to the console for debug purposes.
+    pub fn display_matrix(a: &Matrix) {
+        for i in 0..a.num_rows() {
+            for j in 0..a.num_columns() {
+                print!("{} ", a.entry(i, j));
+            }
+            println!("\n");
+        }
+        println!("------------------------");
+    }
+}
+
+
+/// Returns the n x n identity matrix
+/// which has ones on the diagonal and zeros everywhere else.
+pub fn identity_matrix(n: usize) -> Matrix {
+    Matrix {
+        rows: (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect()
+    }
+}
+
+/// Returns the block-diagonal matrix with the passed blocks along the diagonal 
+/// and zeros everywhere else, i.e. the direct sum of the blocks.
+///
+/// The blocks can be rectangular, the number of rows (columns) of the result 
+/// is the sum of the numbers of rows (columns) of the blocks.
+pub fn block_diagonal(blocks: &[Matrix]) -> Matrix {
+    let num_columns = blocks.iter().map(|b| b.num_columns()).sum();
+    let mut rows = Vec::<Vec<f32>>::new();
+
+    // the block to be placed next starts in this column
+    let mut column_offset = 0;
+    for block in blocks.iter() {
+        for i in 0..block.num_rows() {
+            let mut row = vec![0.0; num_columns];
+            for j in 0..block.num_columns() {
+                row[column_offset + j] = block.entry(i, j);
+            }
+            rows.push(row);
+        }
+        column_offset += block.num_columns();
+    }
+
+    Matrix {
+        rows
+    }
+}
+
+/// Returns the sum of the two passed matrices.
+///
+/// If the matrices do not have the same dimensions, an error variant is returned.
+pub fn add(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    if a.num_rows() != b.num_rows() || a.num_columns() != b.num_columns() {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+
+    Matrix::new(
+        a.rows.iter().zip(b.rows.iter())
+            .map(|(row_a, row_b)| row_a.iter().zip(row_b.iter()).map(|(x, y)| x + y).collect())
+            .collect()
+    )
+}
+
+/// Returns the product a * b of the two passed matrices.
+///
+/// If the number of columns of a does not match the number of rows of b, 
+/// an error variant is returned.
+pub fn multiply(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    if a.num_columns() != b.num_rows() {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+
+    // entry (i, j) of the product is the sum of the products of row i of a and column j of b
+    Matrix::new(
+        (0..a.num_rows()).map(|i| 
+            (0..b.num_columns()).map(|j|
+                (0..a.num_columns()).map(|k| a.entry(i, k) * b.entry(k, j)).sum()
+            ).collect()
+        ).collect()
+    )
+}
+
+
+
+/// Orthonormalizes the passed vectors using the (modified) Gram-Schmidt process,
+/// i.e. returns orthonormal vectors spanning the same space.
+///
+/// Vectors that are (numerically) linearly dependent on the previous ones are dropped,
+/// so the result can contain fewer vectors than the input.
+/// All vectors are expected to have the same length.
+pub fn gram_schmidt(vectors: &[Vec<f32>]) -> Vec<Vec<f32>> {
+    let mut result = Vec::<Vec<f32>>::new();
+
+    for v in vectors.iter() {
+        // subtract the projections onto all previously computed basis vectors
+        let mut w = v.clone();
+        for u in result.iter() {
+            let projection: f32 = w.iter().zip(u.iter()).map(|(x, y)| x * y).sum();
+            for k in 0..w.len() {
+                w[k] -= projection * u[k];
+            }
+        }
+
+        let norm = w.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 1e-6 {
+            result.push(scale_vector(&w, 1.0 / norm));
+        }
+    }
+
+    result
+}
+
+
+
+/// Solves the linear system a * x = b for a matrix a and a right-hand side b
+/// by transforming the augmented matrix [a | b] into upper triangular form.
+///
+/// If the system has no or infinitely many solutions, an error variant is returned.
+/// This is also the case if the length of b does not match the number of rows of a.
+pub fn solve(a: &Matrix, b: &[f32]) -> Result<Vec<f32>, MatrixError> {
+    if a.num_rows() != b.len() {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+
+    let b_column = Matrix::new(b.iter().map(|&x| vec![x]).collect())?;
+
+    match a.augment(&b_column)?.describe_solution(a.num_columns()) {
+        SolutionSet::Unique(x) => Ok(x),
+        _ => Err(MatrixError::NoUniqueSolutionError)
+    }
+}
+
+/// Determines whether the linear system a * x = b has at least one solution 
+/// without computing one.
+///
+/// By the Rouché-Capelli theorem, this is the case iff a has the same rank as the augmented matrix [a | b]
+/// where the ranks are the numbers of pivot columns after transformation to upper triangular form.
+///
+/// If the length of b does not match the number of rows of a, an error variant is returned.
+pub fn is_consistent(a: &Matrix, b: &[f32]) -> Result<bool, MatrixError> {
+    if a.num_rows() != b.len() {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+
+    let b_column = Matrix::new(b.iter().map(|&x| vec![x]).collect())?;
+
+    Ok(a.pivot_columns().len() == a.augment(&b_column)?.pivot_columns().len())
+}
+
+/// Solves the linear system a * x = b exactly for a matrix a with rational entries
+/// and a rational right-hand side b.
+///
+/// Performs Gauss-Jordan elimination on the augmented matrix [a | b] over the rationals, 
+/// so no rounding takes place and integer systems get exact integer/fraction answers.
+///
+/// If the system has no or infinitely many solutions, an error variant is returned.
+/// This is also the case if the rows of a do not have uniform length 
+/// or the length of b does not match the number of rows of a.
+pub fn solve_exact(a: &[Vec<Rational>], b: &[Rational]) -> Result<Vec<Rational>, MatrixError> {
+    if a.len() != b.len() {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+
+    let num_unknowns = if a.is_empty() { 0 } else { a[0].len() };
+    if a.iter().any(|row| row.len() != num_unknowns) {
+        return Err(MatrixError::NonUniformRowLengthError);
+    }
+
+    // augmented matrix [a | b]
+    let mut rows = a.iter().zip(b.iter())
+        .map(|(row, &b_i)| { let mut augmented_row = row.clone(); augmented_row.push(b_i); augmented_row })
+        .collect::<Vec<Vec<Rational>>>();
+
+    let mut current_pivot_row = 0;
+    for j in 0..num_unknowns {
+        // find a row with a non-zero entry in column j among the rows without pivot
+        let pivot_row = match (current_pivot_row..rows.len()).find(|&i| !rows[i][j].is_zero()) {
+            Some(i) => i,
+            None => return Err(MatrixError::NoUniqueSolutionError) // free variable
+        };
+        rows.swap(current_pivot_row, pivot_row);
+
+        // normalize the pivot row
+        let pivot = rows[current_pivot_row][j];
+        for entry in rows[current_pivot_row].iter_mut() {
+            *entry = *entry / pivot;
+        }
+
+        // eliminate column j in all other rows
+        let pivot_row = rows[current_pivot_row].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != current_pivot_row && !row[j].is_zero() {
+                let factor = row[j];
+                for (entry, &pivot_entry) in row.iter_mut().zip(pivot_row.iter()) {
+                    *entry = *entry - factor * pivot_entry;
+                }
+            }
+        }
+
+        current_pivot_row += 1;
+    }
+
+    // remaining rows read 0 = b_i, so the system is inconsistent if any such b_i is non-zero
+    if rows[current_pivot_row..].iter().any(|row| !row[num_unknowns].is_zero()) {
+        return Err(MatrixError::NoUniqueSolutionError);
+    }
+
+    Ok(rows[0..num_unknowns].iter().map(|row| row[num_unknowns]).collect())
+}
+
+/// Computes the inverse of the passed square integer matrix over the prime field Z/pZ
+/// using Gauss-Jordan elimination, where dividing by a pivot means multiplying with its modular inverse.
+///
+/// The entries of the result are from {0, ..., p-1}.
+/// Returns None if the matrix is not square, singular modulo p, 
+/// or if p is not a prime (that fits into an i32).
+pub fn inverse_mod(a: &[Vec<i64>], p: i64) -> Option<Vec<Vec<i64>>> {
+    if p > i32::MAX as i64 || !is_prime(p as i32) {
+        return None;
+    }
+
+    let n = a.len();
+    if a.iter().any(|row| row.len() != n) {
+        return None;
+    }
+
+    let reduce = |x: i64| reduce_mod_wide(x, p);
+
+    // augmented matrix [a | I] with reduced entries
+    let mut m = (0..n)
+        .map(|i| (0..(2*n)).map(|j| if j < n { reduce(a[i][j]) } else if j - n == i { 1 } else { 0 }).collect())
+        .collect::<Vec<Vec<i64>>>();
+
+    for k in 0..n {
+        // a missing pivot means that the matrix is singular modulo p
+        let pivot_row = (k..n).find(|&i| m[i][k] != 0)?;
+        m.swap(k, pivot_row);
+
+        // normalize the pivot row, the pivot is non-zero and thus invertible in Z/pZ
+        let pivot_inverse = mod_inverse(m[k][k] as i32, p as i32).unwrap() as i64;
+        for entry in m[k].iter_mut() {
+            *entry = reduce(*entry * pivot_inverse);
+        }
+
+        // eliminate column k in all other rows
+        let pivot_row = m[k].clone();
+        for (i, row) in m.iter_mut().enumerate() {
+            if i != k && row[k] != 0 {
+                let factor = row[k];
+                for (entry, &pivot_entry) in row.iter_mut().zip(pivot_row.iter()) {
+                    *entry = reduce(*entry - factor * pivot_entry);
+                }
+            }
+        }
+    }
+
+    Some(m.iter().map(|row| row[n..].to_vec()).collect())
+}
+
+/// Computes the rank of the passed integer matrix over the prime field Z/pZ
+/// using Gaussian elimination, where dividing by a pivot means multiplying with its modular inverse.
+///
+/// The rank modulo p may be smaller than the rank over the rationals,
+/// e.g. the rows (1, 1) and (1, -1) are linearly dependent modulo 2.
+/// Returns None if the rows do not have uniform length or if p is not a prime (that fits into an i32).
+pub fn rank_mod(a: &[Vec<i64>], p: i64) -> Option<usize> {
+    if p > i32::MAX as i64 || !is_prime(p as i32) {
+        return None;
+    }
+
+    let num_columns = if a.is_empty() { 0 } else { a[0].len() };
+    if a.iter().any(|row| row.len() != num_columns) {
+        return None;
+    }
+
+    let reduce = |x: i64| reduce_mod_wide(x, p);
+
+    let mut m = a.iter().map(|row| row.iter().map(|&x| reduce(x)).collect()).collect::<Vec<Vec<i64>>>();
+    let mut rank = 0;
+
+    for k in 0..num_columns {
+        // columns without a pivot below the current pivot rows do not increase the rank
+        let pivot_row = match (rank..m.len()).find(|&i| m[i][k] != 0) {
+            Some(i) => i,
+            None => continue
+        };
+        m.swap(rank, pivot_row);
+
+        // normalize the pivot row, the pivot is non-zero and thus invertible in Z/pZ
+        let pivot_inverse = mod_inverse(m[rank][k] as i32, p as i32).unwrap() as i64;
+        for entry in m[rank][k..].iter_mut() {
+            *entry = reduce(*entry * pivot_inverse);
+        }
+
+        // eliminate column k below the pivot
+        let (upper, lower) = m.split_at_mut(rank + 1);
+        let pivot_row = &upper[rank];
+        for row in lower.iter_mut() {
+            if row[k] != 0 {
+                let factor = row[k];
+                for (entry, &pivot_entry) in row[k..].iter_mut().zip(pivot_row[k..].iter()) {
+                    *entry = reduce(*entry - factor * pivot_entry);
+                }
+            }
+        }
+
+        rank += 1;
+    }
+
+    Some(rank)
+}
+
+/// Computes the determinant of the passed square integer matrix 
+/// using the fraction-free Bareiss algorithm.
+///
+/// Like Gaussian elimination, the algorithm eliminates the entries below the pivots,
+/// but it updates the entries as m_ij = (m_ij * m_kk - m_ik * m_kj) / p (where p is the previous pivot).
+/// These divisions are always exact, so all intermediate values are integers (minors of the matrix)
+/// and the determinant is the last pivot. Row switches on zero pivots flip its sign.
+///
+/// The matrix is expected to be square, the determinant of the empty matrix is 1.
+pub fn bareiss_determinant(a: &[Vec<i64>]) -> i64 {
+    let n = a.len();
+    let mut m = a.to_vec();
+
+    let mut sign = 1;
+    let mut previous_pivot = 1;
+
+    for k in 0..n {
+        // find a row with a non-zero pivot, if there is none, the matrix is singular
+        let pivot_row = match (k..n).find(|&i| m[i][k] != 0) {
+            Some(i) => i,
+            None => return 0
+        };
+        if pivot_row != k {
+            m.swap(k, pivot_row);
+            sign = -sign;
+        }
+
+        for i in (k+1)..n {
+            for j in (k+1)..n {
+                m[i][j] = (m[i][j] * m[k][k] - m[i][k] * m[k][j]) / previous_pivot;
+            }
+        }
+
+        previous_pivot = m[k][k];
+    }
+
+    if n == 0 { 1 } else { sign * m[n-1][n-1] }
+}
+
+/// Computes the diagonal of the Smith normal form of the passed integer matrix,
+/// i.e. its invariant factors d_1, d_2, ..., d_k (k the minimum of the numbers of rows and columns)
+/// where every d_i divides d_(i+1).
+///
+/// The matrix is reduced using integer row and column operations only, so the computation is exact.
+/// For every diagonal position, the entry of smallest absolute value is moved there
+/// and the other entries of its row and column are reduced by division with remainder.
+/// This is the Euclidean algorithm performed via row and column operations
+/// and is repeated until the diagonal entry is the gcd of its row and column
+/// (and divides all remaining entries).
+///
+/// The invariant factors are non-negative, trailing zeros correspond to the rank deficiency of the matrix.
+/// All rows are expected to have the same length.
+pub fn smith_normal_form_diagonal(a: &[Vec<i64>]) -> Vec<i64> {
+    let mut m = a.to_vec();
+    let num_rows = m.len();
+    let num_columns = if num_rows == 0 { 0 } else { m[0].len() };
+    let k = if num_rows < num_columns { num_rows } else { num_columns };
+
+    let mut result = Vec::<i64>::new();
+
+    for t in 0..k {
+        loop {
+            // find the non-zero entry of smallest absolute value in the remaining submatrix
+            let mut pivot = None;
+            for i in t..num_rows {
+                for j in t..num_columns {
+                    if m[i][j] != 0 && pivot.is_none_or(|(p, q): (usize, usize)| m[i][j].abs() < m[p][q].abs()) {
+                        pivot = Some((i, j));
+                    }
+                }
+            }
+
+            // the remaining submatrix is zero, so all remaining invariant factors are 0
+            let (p, q) = match pivot {
+                Some(position) => position,
+                None => {
+                    result.resize(k, 0);
+                    return result;
+                }
+            };
+
+            // move the pivot to position (t, t)
+            m.swap(t, p);
+            for row in m.iter_mut() {
+                row.swap(t, q);
+            }
+
+            // reduce the entries below and right of the pivot by division with remainder
+            let mut remainders_left = false;
+            let (upper, lower) = m.split_at_mut(t + 1);
+            let pivot_row = &upper[t];
+            for row in lower.iter_mut() {
+                let factor = row[t] / pivot_row[t];
+                for (entry, &pivot_entry) in row[t..].iter_mut().zip(pivot_row[t..].iter()) {
+                    *entry -= factor * pivot_entry;
+                }
+                remainders_left = remainders_left || row[t] != 0;
+            }
+            for j in (t+1)..num_columns {
+                let factor = m[t][j] / m[t][t];
+                for row in m[t..].iter_mut() {
+                    row[j] -= factor * row[t];
+                }
+                remainders_left = remainders_left || m[t][j] != 0;
+            }
+
+            // the non-zero remainders are smaller than the pivot, so they become the next pivot
+            if remainders_left {
+                continue;
+            }
+
+            /*
+            * Now the pivot is the only non-zero entry in its row and column.
+            * If it does not divide some remaining entry, adding that entry's row to the pivot row
+            * makes the next reduction produce a smaller pivot.
+            */
+            let non_divisible_row = ((t+1)..num_rows)
+                .find(|&i| ((t+1)..num_columns).any(|j| m[i][j] % m[t][t] != 0));
+
+            match non_divisible_row {
+                Some(i) => {
+                    let row_i = m[i].clone();
+                    for (entry, &other_entry) in m[t][t..].iter_mut().zip(row_i[t..].iter()) {
+                        *entry += other_entry;
+                    }
+                },
+                None => break
+            }
+        }
+
+        result.push(m[t][t].abs());
+    }
+
+    result
+}
+
+/// Solves the linear system a * x = rhs for a tridiagonal n x n matrix a 
+/// using the Thomas algorithm, which runs in O(n).
+///
+/// The matrix is passed as its three bands: 
+/// the n-1 entries below the diagonal (sub), the n diagonal entries (diag) 
+/// and the n-1 entries above the diagonal (sup),
+/// i.e. row i of a is (..., sub[i-1], diag[i], sup[i], ...).
+///
+/// The Thomas algorithm is Gaussian elimination without row switches,
+/// so it can fail with an error variant on a zero pivot even if the system is uniquely solvable.
+/// This does not happen for diagonally dominant matrices.
+/// If the band lengths are not consistent with each other or with rhs, an error variant is returned.
+pub fn solve_tridiagonal(sub: &[f32], diag: &[f32], sup: &[f32], rhs: &[f32]) -> Result<Vec<f32>, MatrixError> {
+    let n = diag.len();
+
+    if rhs.len() != n || (n > 0 && (sub.len() != n - 1 || sup.len() != n - 1)) {
+        return Err(MatrixError::DimensionMismatchError);
+    }
+    if n == 0 {
+        return Ok(vec![]);
+    }
+
+    /*
+    * Forward sweep: eliminate the subdiagonal.
+    * Afterwards, row i reads x_i + modified_sup[i] * x_(i+1) = modified_rhs[i].
+    */
+    let mut modified_sup = vec![0.0; n];
+    let mut modified_rhs = vec![0.0; n];
+
+    for i in 0..n {
+        // pivot element after subtracting the appropriate multiple of the previous row
+        let pivot = if i == 0 { diag[0] } else { diag[i] - sub[i-1] * modified_sup[i-1] };
+
+        if pivot == 0.0 {
+            return Err(MatrixError::ZeroPivotError);
+        }
+
+        if i < n - 1 {
+            modified_sup[i] = sup[i] / pivot;
+        }
+        modified_rhs[i] = if i == 0 { rhs[0] / pivot } else { (rhs[i] - sub[i-1] * modified_rhs[i-1]) / pivot };
+    }
+
+    // back substitution
+    let mut x = modified_rhs;
+    for i in (0..(n-1)).rev() {
+        x[i] -= modified_sup[i] * x[i+1];
+    }
+
+    Ok(x)
+}
+
+/// Matrices are approximately equal if they have the same dimensions 
+/// and all their entries are approximately equal.
+impl ApproxEq for Matrix {
+    fn approx_eq(self: &Self, other: &Self, epsilon: f64) -> bool {
+        self.rows.len() == other.rows.len() 
+            && self.rows.iter().zip(other.rows.iter()).all(|(row, other_row)| row.approx_eq(other_row, epsilon))
+    }
+}
+
+/// Describes the set of solutions of a linear system.
+#[derive(PartialEq, Debug, Clone)]
+pub enum SolutionSet {
+    /// The system has exactly one solution.
+    Unique(Vec<f32>),
+    /// The system has infinitely many solutions, 
+    /// namely the particular solution plus any linear combination of the basis vectors
+    /// of the solution space of the homogeneous system.
+    Infinite {
+        particular: Vec<f32>,
+        basis: Vec<Vec<f32>>
+    },
+    /// The system is inconsistent, i.e. has no solution.
+    None
+}
+
+/// Type modelling all different kinds of errors
+/// that can occur when working with real matrices. 
+#[derive(PartialEq, Debug, Clone)]
+pub enum MatrixError{
+    NonUniformRowLengthError,
+    /// Returned when the dimensions of the involved matrices
+    /// do not fit the requested operation.
+    DimensionMismatchError,
+    /// Returned when attempting an operation that is only defined for square matrices
+    /// on a non-square matrix.
+    NotSquareError,
+    /// Returned when attempting an operation that is only defined for matrices with integer entries
+    /// on a matrix with non-integer entries.
+    NonIntegerEntryError,
+    /// Returned when a linear system that is supposed to be solved 
+    /// has no or infinitely many solutions.
+    NoUniqueSolutionError,
+    /// Returned when an elimination procedure without row switches encounters a zero pivot element.
+    ZeroPivotError,
+    /// Returned when attempting an operation that is only defined for invertible matrices
+    /// on a singular matrix.
+    SingularMatrixError
+}
+
+
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::*;
+
+    #[test]
+    fn matrix_construction_test() {
+        println!("Creating test 3x3 matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 426.0]
+            ]
+        );
+
+        assert!(test_matrix.is_ok());
+
+        println!("Attempting to create a matrix with rows of non-uniform length.");
+
+        let errornous_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0],
+                vec![426.0, 426.0, 426.0]
+            ]
+        );
+
+        assert_eq!(errornous_matrix, Err(MatrixError::NonUniformRowLengthError));
+    }
+
+
+
+    #[test]
+    fn rows_and_columns_test() {
+        println!("Creating test 3x3 matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that 2nd row is extracted correctly.");
+
+        assert_eq!(test_matrix.row(1), vec![4.0, 5.0, 6.0]);
+
+        println!("Asserting that 3rd column is extracted correctly.");
+
+        assert_eq!(test_matrix.column(2), vec![3.0, 6.0, 426.0]);
+    }
+
+    #[test]
+    fn reshape_test() {
+        println!("Creating test 3x3 matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that the middle row is correctly deleted.");
+
+        assert_eq!(
+            test_matrix.delete_row(1),
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0, 3.0],
+                    vec![7.0, 8.0, 426.0]
+                ]
+            )
+        );
+
+        println!("Asserting that the first column is correctly deleted.");
+
+        assert_eq!(
+            test_matrix.delete_column(0),
+            Matrix::new(
+                vec![
+                    vec![2.0, 3.0],
+                    vec![5.0, 6.0],
+                    vec![8.0, 426.0]
+                ]
+            )
+        );
+
+        println!("Asserting that the only row or column and rows or columns out of range cannot be deleted.");
+
+        let single_row = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        assert_eq!(single_row.delete_row(0), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(single_row.delete_column(1), Matrix::new(vec![vec![1.0]]));
+        assert_eq!(single_row.delete_column(1).unwrap().delete_column(0), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(test_matrix.delete_row(3), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(test_matrix.delete_column(3), Err(MatrixError::DimensionMismatchError));
+
+        println!("Reshaping a 2x3 matrix into a 3x2 matrix.");
+
+        let wide_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 426.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(
+            wide_matrix.reshape(3, 2),
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0],
+                    vec![3.0, 4.0],
+                    vec![5.0, 426.0]
+                ]
+            )
+        );
+
+        println!("Asserting that reshaping to a shape with a different number of entries fails.");
+
+        assert_eq!(wide_matrix.reshape(2, 2), Err(MatrixError::DimensionMismatchError));
+
+        println!("Asserting that reshaping to a shape with a zero dimension fails.");
+
+        let empty_row = Matrix::new(vec![vec![]]).unwrap();
+        assert_eq!(empty_row.reshape(3, 0), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(empty_row.reshape(0, 3), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(wide_matrix.reshape(0, 6), Err(MatrixError::DimensionMismatchError));
+    }
+
+    #[test]
+    fn augment_stack_test() {
+        println!("Creating 2x3 and 1x3 test matrices.");
+
+        let upper_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0]
+            ]
+        ).unwrap();
+        let lower_matrix = Matrix::new(
+            vec![
+                vec![7.0, 8.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that stacking the two matrices yields a 3x3 matrix.");
+
+        assert_eq!(
+            upper_matrix.stack(&lower_matrix),
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0, 3.0],
+                    vec![4.0, 5.0, 6.0],
+                    vec![7.0, 8.0, 426.0]
+                ]
+            )
+        );
+
+        println!("Asserting that matrices with different numbers of columns cannot be stacked.");
+
+        assert_eq!(
+            upper_matrix.stack(&upper_matrix.delete_column(0).unwrap()),
+            Err(MatrixError::DimensionMismatchError)
+        );
+
+        println!("Augmenting the 2x3 matrix with a column.");
+
+        let column = Matrix::new(
+            vec![
+                vec![426.0],
+                vec![0.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(
+            upper_matrix.augment(&column),
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0, 3.0, 426.0],
+                    vec![4.0, 5.0, 6.0, 0.0]
+                ]
+            )
+        );
+
+        println!("Asserting that matrices with different numbers of rows cannot be augmented.");
+
+        assert_eq!(upper_matrix.augment(&lower_matrix), Err(MatrixError::DimensionMismatchError));
+    }
+
+    #[test]
     fn scale_row_test() {
         println!("Creating 3x4 test matrix.");
 
         let mut test_matrix = Matrix::new(
             vec![
-                vec![1.0, 2.0, 3.0, 4.0],
-                vec![5.0, 6.0, 7.0, 426.0],
-                vec![9.0, 10.0, 11.0, 12.0]
+                vec![1.0, 2.0, 3.0, 4.0],
+                vec![5.0, 6.0, 7.0, 426.0],
+                vec![9.0, 10.0, 11.0, 12.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that second row can be correctly scaled by 1.5.");
+
+        test_matrix.scale_row(1, 1.5);
+        assert_eq!(
+            test_matrix.row(1), vec![7.5, 9.0, 10.5, 639.0]
+        );
+    }
+
+    #[test]
+    fn switch_rows_test() {
+        println!("Creating 4x3 test matrix.");
+
+        let mut test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 426.0, 5.0],
+                vec![426.0, 8.0, 426.0],
+                vec![10.0, 11.0, 12.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that rows 1 and 2 can be correctly swapped.");
+
+        test_matrix.switch_rows(1, 2);
+        assert_eq!(
+            test_matrix,
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0, 3.0],
+                    vec![426.0, 8.0, 426.0],
+                    vec![4.0, 426.0, 5.0],
+                    vec![10.0, 11.0, 12.0]
+                ]
+            ).unwrap()
+        )
+    }
+
+    #[test]
+    fn add_scalar_multiple_test() {
+        println!("Creating test 3x2 matrix.");
+
+        let mut test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 3.0],
+                vec![0.0, 2.0],
+                vec![4.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Adding 3.2 times row 0 to row 1.");
+
+        test_matrix.add_scalar_multiple(1, 3.2, 0);
+        assert_eq!(
+            test_matrix,    
+            Matrix::new(
+                vec![
+                    vec![1.0, 3.0],
+                    vec![3.2, 11.6],
+                    vec![4.0, 426.0]
+                ]
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn is_zero_row_column_test(){
+        println!("Creating 3x4 test matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 426.0, 0.0],
+                vec![0.0, 0.0, 0.0, 0.0],
+                vec![9.0, 10.0, 11.0, 0.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that zero row is correctly classified.");
+
+        assert_eq!(test_matrix.is_zero_row(1), true);
+
+        println!("Asserting that non-zero row is correctly classified.");
+
+        assert_eq!(test_matrix.is_zero_row(2), false);
+
+        println!("Asserting that zero column is correctly classified.");
+
+        assert_eq!(test_matrix.is_zero_column(3), true);
+
+        println!("Asserting that non-zero column is correctly classified.");
+
+        assert_eq!(test_matrix.is_zero_column(0), false);
+
+        println!("Asserting that all rows and columns are classified correctly.");
+
+        assert_eq!((0..3).map(|i| test_matrix.is_zero_row(i)).collect::<Vec<bool>>(), vec![false, true, false]);
+        assert_eq!(
+            (0..4).map(|j| test_matrix.is_zero_column(j)).collect::<Vec<bool>>(), 
+            vec![false, false, false, true]
+        );
+        assert!((0..3).all(|i| test_matrix.is_zero_row(i) == test_matrix.row(i).iter().all(|&x| x == 0.0)));
+        assert!((0..4).all(|j| test_matrix.is_zero_column(j) == test_matrix.column(j).iter().all(|&x| x == 0.0)));
+    }
+
+    #[test]
+    fn test_first_row_without_zero_at_beginning_from() {
+        println!("Creating 3x3 test matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0]
+            ]
+        ).unwrap();
+
+        println!("Searching for non zero elements in different columns.");
+
+        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(0, 0), Some(0));
+        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(0, 1), None);
+        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(1, 0), Some(2));
+        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(2, 0), None);
+    }
+
+    #[test]
+    fn test_first_non_zero_row_beginning_from() {
+        println!("Creating 5x3 test matrix.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 426.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+            ]
+        ).unwrap();
+
+        println!("Searching for next non-zero row from various different starting rows.");
+
+        assert_eq!(test_matrix.next_non_zero_row_beginning_from(0), Some(0));
+        assert_eq!(test_matrix.next_non_zero_row_beginning_from(1), Some(2));
+        assert_eq!(test_matrix.next_non_zero_row_beginning_from(2), Some(2));
+        assert_eq!(test_matrix.next_non_zero_row_beginning_from(3), None);
+        assert_eq!(test_matrix.next_non_zero_row_beginning_from(4), None);
+    }
+
+    #[test]
+    fn test_to_upper_triangular() {
+        println!("Creating 3x4 test matrix.");
+
+        let mut test_matrix1 = Matrix::new(
+            vec![
+                vec![1.0, 1.0, -1.0, 0.0],
+                vec![2.0, 1.0, 0.0, 1.0],
+                vec![3.0, 1.0, 2.0, 0.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that upper triangular form of the first test matrix is correctly computed.");
+
+        test_matrix1.to_upper_triangular();
+        assert_eq!(
+            test_matrix1,
+            Matrix::new(
+                vec![
+                    vec![1.0, 0.0, 0.0, 3.0],
+                    vec![0.0, 1.0, 0.0, -5.0],
+                    vec![0.0, 0.0, 1.0, -2.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Creating 3x5 test matrix.");
+
+        let mut test_matrix2 = Matrix::new(
+            vec![
+                vec![3.0, -3.0, 3.0, 6.0, 3.0],
+                vec![1.0, -1.0, -3.0, 0.0, -8.0],
+                vec![2.0, -2.0, -2.0, 2.0, 5.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that upper triangular form of the second test matrix is correctly computed.");
+
+        test_matrix2.to_upper_triangular();
+        assert_eq!(
+            test_matrix2,
+            Matrix::new(
+                vec![
+                    vec![1.0, -1.0, 0.0, 1.5, 0.0],
+                    vec![0.0, 0.0, 1.0, 0.5, 0.0],
+                    vec![0.0, 0.0, 0.0, 0.0, 1.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Creating 4x5 test matrix.");
+
+        let mut test_matrix3 = Matrix::new(
+            vec![
+                vec![1.0, 1.0, 1.0, 0.0, 3.0],
+                vec![-11.0, 1.0, 9.0, 2.0, -15.0],
+                vec![3.0, 0.0, -3.0, 0.0, 3.0],
+                vec![8.0, 2.0, -4.0, -1.0, 13.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that upper triangular form of the third test matrix is correctly computed.");
+
+        test_matrix3.to_upper_triangular();
+        assert_eq!(
+            test_matrix3,
+            Matrix::new(
+                vec![
+                    vec![1.0, 0.0, 0.0, 0.0, 2.0],
+                    vec![0.0, 1.0, 0.0, 0.0, 0.0],
+                    vec![0.0, 0.0, 1.0, 0.0, 1.0],
+                    vec![0.0, 0.0, 0.0, 1.0, -1.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Creating 3x4 test matrix.");
+
+        let mut test_matrix4 = Matrix::new(
+            vec![
+                vec![3.0, 0.0, 3.0, 0.0],
+                vec![3.0, 1.0, 1.0, 0.0],
+                vec![2.0, 1.0, 0.0, 0.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that upper triangular form of the fourth test matrix is correctly computed.");
+
+        test_matrix4.to_upper_triangular();
+        assert_eq!(
+            test_matrix4,
+            Matrix::new(
+                vec![
+                    vec![1.0, 0.0, 1.0, 0.0],
+                    vec![0.0, 1.0, -2.0, 0.0],
+                    vec![0.0, 0.0, 0.0, 0.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Assert idempotency of operation.");
+
+        // at this point, the matrix already is in upper triangular form
+        test_matrix4.to_upper_triangular();
+
+        test_matrix4.to_upper_triangular();
+        assert_eq!(
+            test_matrix4,
+            Matrix::new(
+                vec![
+                    vec![1.0, 0.0, 1.0, 0.0],
+                    vec![0.0, 1.0, -2.0, 0.0],
+                    vec![0.0, 0.0, 0.0, 0.0]
+                ]
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        let test_matrices = [
+            Matrix::new(
+                vec![
+                    vec![2.0, 0.0, 1.0],
+                    vec![1.0, 3.0, 2.0],
+                    vec![1.0, 1.0, 2.0]
+                ]
+            ).unwrap(),
+            Matrix::new(
+                vec![
+                    vec![0.0, 1.0, 2.0],
+                    vec![3.0, -1.0, 4.0],
+                    vec![5.0, 2.0, -3.0]
+                ]
+            ).unwrap(),
+            Matrix::new(
+                vec![
+                    vec![1.0, 2.0, 0.0, 1.0],
+                    vec![0.0, 1.0, 3.0, -1.0],
+                    vec![2.0, 0.0, 1.0, 4.0],
+                    vec![1.0, -1.0, 2.0, 0.0]
+                ]
+            ).unwrap(),
+            Matrix::new(
+                vec![
+                    vec![3.0, 0.0, 0.0, 426.0],
+                    vec![0.0, 2.0, 0.0, 0.0],
+                    vec![0.0, 0.0, 1.0, 0.0],
+                    vec![0.0, 0.0, 0.0, -1.0]
+                ]
+            ).unwrap()
+        ];
+        let expected_determinants = [6.0, 51.0, -30.0, -6.0];
+
+        println!("Asserting that cofactor expansion yields the exact determinants of some 3x3 and 4x4 matrices.");
+
+        for (a, &det) in test_matrices.iter().zip(expected_determinants.iter()) {
+            assert_eq!(a.determinant_cofactor(), Ok(det));
+        }
+
+        println!("Asserting that the elimination-based determinant agrees with cofactor expansion.");
+
+        for a in test_matrices.iter() {
+            let det = a.determinant().unwrap();
+            let det_cofactor = a.determinant_cofactor().unwrap();
+            assert!((det - det_cofactor).abs() < 1e-4);
+        }
+
+        println!("Asserting that cofactor expansion yields exactly 0 for a singular matrix.");
+
+        let singular_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 9.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(singular_matrix.determinant_cofactor(), Ok(0.0));
+
+        println!("Asserting that the determinant of a non-square matrix cannot be computed.");
+
+        let non_square_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(non_square_matrix.determinant(), Err(MatrixError::NotSquareError));
+        assert_eq!(non_square_matrix.determinant_cofactor(), Err(MatrixError::NotSquareError));
+    }
+
+    #[test]
+    fn test_to_upper_triangular_degenerate_shapes() {
+        println!("Creating 3x1 test matrix with a zero in the first row.");
+
+        let mut column_matrix = Matrix::new(
+            vec![
+                vec![0.0],
+                vec![2.0],
+                vec![426.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that the first non-zero entry is normalized and moved to the top.");
+
+        column_matrix.to_upper_triangular();
+        assert_eq!(
+            column_matrix,
+            Matrix::new(
+                vec![
+                    vec![1.0],
+                    vec![0.0],
+                    vec![0.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Creating 1x4 test matrix.");
+
+        let mut row_matrix = Matrix::new(
+            vec![
+                vec![0.0, 0.0, 2.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that the single row is normalized.");
+
+        row_matrix.to_upper_triangular();
+        assert_eq!(
+            row_matrix,
+            Matrix::new(
+                vec![
+                    vec![0.0, 0.0, 1.0, 213.0]
+                ]
+            ).unwrap()
+        );
+
+        println!("Creating 4x4 zero matrix.");
+
+        let mut zero_matrix = Matrix::new(vec![vec![0.0; 4]; 4]).unwrap();
+
+        println!("Assert that the zero matrix stays zero.");
+
+        zero_matrix.to_upper_triangular();
+        assert_eq!(zero_matrix, Matrix::new(vec![vec![0.0; 4]; 4]).unwrap());
+
+        println!("Creating 3x3 test matrix with a zero row above non-zero rows.");
+
+        let mut test_matrix = Matrix::new(
+            vec![
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 2.0, 0.0],
+                vec![4.0, 0.0, 0.0]
+            ]
+        ).unwrap();
+
+        println!("Assert that the zero row is moved to the bottom.");
+
+        test_matrix.to_upper_triangular();
+        assert_eq!(
+            test_matrix,
+            Matrix::new(
+                vec![
+                    vec![1.0, 0.0, 0.0],
+                    vec![0.0, 1.0, 0.0],
+                    vec![0.0, 0.0, 0.0]
+                ]
+            ).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pivot_columns() {
+        println!("Creating 3x4 test matrix of rank 2.");
+
+        let test_matrix = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 1.0, 1.0],
+                vec![2.0, 4.0, 0.0, 426.0],
+                vec![3.0, 6.0, 1.0, 427.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that the first and third column are the pivot columns.");
+
+        assert_eq!(test_matrix.pivot_columns(), vec![0, 2]);
+
+        println!("Asserting that the zero matrix has no pivot columns.");
+
+        assert_eq!(Matrix::new(vec![vec![0.0; 3]; 2]).unwrap().pivot_columns(), vec![]);
+    }
+
+    #[test]
+    fn test_matrix_arithmetic() {
+        println!("Creating 2x2 test matrices.");
+
+        let a = Matrix::new(
+            vec![
+                vec![1.0, 2.0],
+                vec![3.0, 4.0]
+            ]
+        ).unwrap();
+        let b = Matrix::new(
+            vec![
+                vec![0.0, 1.0],
+                vec![1.0, 426.0]
+            ]
+        ).unwrap();
+
+        println!("Asserting that sum and product are computed correctly.");
+
+        assert_eq!(
+            add(&a, &b),
+            Matrix::new(
+                vec![
+                    vec![1.0, 3.0],
+                    vec![4.0, 430.0]
+                ]
+            )
+        );
+        assert_eq!(
+            multiply(&a, &b),
+            Matrix::new(
+                vec![
+                    vec![2.0, 853.0],
+                    vec![4.0, 1707.0]
+                ]
+            )
+        );
+
+        println!("Asserting that the identity is neutral with respect to multiplication.");
+
+        assert_eq!(multiply(&a, &identity_matrix(2)), Ok(a.clone()));
+        assert_eq!(a.pow(0), Ok(identity_matrix(2)));
+
+        println!("Asserting that the third power is computed correctly.");
+
+        assert_eq!(
+            a.pow(3),
+            Matrix::new(
+                vec![
+                    vec![37.0, 54.0],
+                    vec![81.0, 118.0]
+                ]
+            )
+        );
+
+        println!("Asserting that matrices of non-matching dimensions cannot be added or multiplied.");
+
+        let c = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+
+        assert_eq!(add(&a, &c), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(multiply(&a, &c), Err(MatrixError::DimensionMismatchError));
+        assert_eq!(c.pow(2), Err(MatrixError::NotSquareError));
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        println!("Computing the characteristic polynomial of a 2x2 matrix.");
+
+        let a = Matrix::new(
+            vec![
+                vec![1.0, 2.0],
+                vec![3.0, 4.0]
+            ]
+        ).unwrap();
+
+        // X^2 - trace * X + det
+        assert_eq!(
+            a.characteristic_polynomial(),
+            Ok(IntPoly::new(&mut vec![-2, -5, 1], Modulus::None).unwrap())
+        );
+
+        println!("Computing the characteristic polynomial of a 3x3 matrix.");
+
+        let b = Matrix::new(
+            vec![
+                vec![2.0, 0.0, 0.0],
+                vec![1.0, 3.0, 0.0],
+                vec![4.0, 426.0, -1.0]
+            ]
+        ).unwrap();
+
+        // (X - 2)(X - 3)(X + 1)
+        assert_eq!(
+            b.characteristic_polynomial(),
+            Ok(IntPoly::new(&mut vec![6, 1, -4, 1], Modulus::None).unwrap())
+        );
+
+        println!("Asserting that matrices with non-integer entries are rejected.");
+
+        assert_eq!(
+            a.scale(0.5).characteristic_polynomial(),
+            Err(MatrixError::NonIntegerEntryError)
+        );
+    }
+
+    #[test]
+    fn test_describe_solution() {
+        println!("Describing the solution of a uniquely solvable system.");
+
+        // x + y = 3, x - y = 1
+        let unique_system = Matrix::new(
+            vec![
+                vec![1.0, 1.0, 3.0],
+                vec![1.0, -1.0, 1.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(unique_system.describe_solution(2), SolutionSet::Unique(vec![2.0, 1.0]));
+
+        println!("Describing the solution of an inconsistent system.");
+
+        // x + y = 1, x + y = 2
+        let inconsistent_system = Matrix::new(
+            vec![
+                vec![1.0, 1.0, 1.0],
+                vec![1.0, 1.0, 2.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(inconsistent_system.describe_solution(2), SolutionSet::None);
+
+        println!("Describing the solution of an underdetermined system.");
+
+        // x + 2y + z = 4, z = 1
+        let underdetermined_system = Matrix::new(
+            vec![
+                vec![1.0, 2.0, 1.0, 4.0],
+                vec![0.0, 0.0, 1.0, 1.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(
+            underdetermined_system.describe_solution(3),
+            SolutionSet::Infinite {
+                particular: vec![3.0, 0.0, 1.0],
+                basis: vec![vec![-2.0, 1.0, 0.0]]
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn describe_solution_too_many_unknowns_test() {
+        println!("Asserting that describe_solution panics if the matrix has too few columns.");
+
+        identity_matrix(2).describe_solution(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn describe_solution_too_few_unknowns_test() {
+        println!("Asserting that describe_solution panics if the matrix has too many columns.");
+
+        Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap().describe_solution(1);
+    }
+
+    #[test]
+    fn test_solve() {
+        println!("Solving a uniquely solvable 3x3 system.");
+
+        let a = Matrix::new(
+            vec![
+                vec![2.0, 1.0, -1.0],
+                vec![-3.0, -1.0, 2.0],
+                vec![-2.0, 1.0, 2.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(solve(&a, &[8.0, -11.0, -3.0]), Ok(vec![2.0, 3.0, -1.0]));
+
+        println!("Asserting that a singular system cannot be solved.");
+
+        let singular_matrix = Matrix::new(
+            vec![
+                vec![1.0, 1.0],
+                vec![2.0, 2.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(solve(&singular_matrix, &[1.0, 2.0]), Err(MatrixError::NoUniqueSolutionError));
+        assert_eq!(solve(&singular_matrix, &[1.0, 3.0]), Err(MatrixError::NoUniqueSolutionError));
+
+        println!("Asserting that a right-hand side of wrong length is rejected.");
+
+        assert_eq!(solve(&a, &[1.0]), Err(MatrixError::DimensionMismatchError));
+    }
+
+    #[test]
+    fn test_solve_tridiagonal() {
+        println!("Solving a 4x4 tridiagonal system.");
+
+        let sub = [1.0, 1.0, 1.0];
+        let diag = [4.0, 4.0, 4.0, 4.0];
+        let sup = [1.0, 1.0, 1.0];
+        let rhs = [5.0, 6.0, 6.0, 5.0];
+
+        let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+
+        println!("Asserting that the result agrees with the dense solver.");
+
+        let a = Matrix::new(
+            vec![
+                vec![4.0, 1.0, 0.0, 0.0],
+                vec![1.0, 4.0, 1.0, 0.0],
+                vec![0.0, 1.0, 4.0, 1.0],
+                vec![0.0, 0.0, 1.0, 4.0]
+            ]
+        ).unwrap();
+        let x_dense = solve(&a, &rhs).unwrap();
+
+        for i in 0..4 {
+            assert!((x[i] - 1.0).abs() < 1e-5);
+            assert!((x[i] - x_dense[i]).abs() < 1e-5);
+        }
+
+        println!("Asserting that inconsistent band lengths are rejected.");
+
+        assert_eq!(
+            solve_tridiagonal(&sub, &diag, &[1.0, 1.0], &rhs),
+            Err(MatrixError::DimensionMismatchError)
+        );
+        assert_eq!(
+            solve_tridiagonal(&sub, &diag, &sup, &[1.0]),
+            Err(MatrixError::DimensionMismatchError)
+        );
+
+        println!("Asserting that a zero pivot is reported.");
+
+        assert_eq!(
+            solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &[1.0, 1.0]),
+            Err(MatrixError::ZeroPivotError)
+        );
+    }
+
+    #[test]
+    fn test_is_reduced_row_echelon() {
+        println!("Asserting that the upper triangular forms of some matrices are in reduced row echelon form.");
+
+        let test_matrices = [
+            Matrix::new(
+                vec![
+                    vec![1.0, 1.0, -1.0, 0.0],
+                    vec![2.0, 1.0, 0.0, 1.0],
+                    vec![3.0, 1.0, 2.0, 0.0]
+                ]
+            ).unwrap(),
+            Matrix::new(
+                vec![
+                    vec![3.0, -3.0, 3.0, 6.0, 3.0],
+                    vec![1.0, -1.0, -3.0, 0.0, -8.0],
+                    vec![2.0, -2.0, -2.0, 2.0, 5.0]
+                ]
+            ).unwrap(),
+            Matrix::new(
+                vec![
+                    vec![3.0, 0.0, 3.0, 0.0],
+                    vec![3.0, 1.0, 1.0, 0.0],
+                    vec![2.0, 1.0, 0.0, 0.0]
+                ]
+            ).unwrap(),
+            Matrix::new(vec![vec![0.0; 4]; 4]).unwrap()
+        ];
+
+        for a in test_matrices.iter() {
+            let mut reduced = a.clone();
+            reduced.to_upper_triangular();
+            assert!(reduced.is_reduced_row_echelon(1e-6));
+        }
+
+        println!("Asserting that matrices violating any of the conditions are detected.");
+
+        let pivot_not_one = Matrix::new(
+            vec![
+                vec![2.0, 0.0],
+                vec![0.0, 1.0]
+            ]
+        ).unwrap();
+        let entry_above_pivot = Matrix::new(
+            vec![
+                vec![1.0, 426.0],
+                vec![0.0, 1.0]
+            ]
+        ).unwrap();
+        let pivots_not_moving_right = Matrix::new(
+            vec![
+                vec![0.0, 1.0],
+                vec![1.0, 0.0]
+            ]
+        ).unwrap();
+        let zero_row_not_at_bottom = Matrix::new(
+            vec![
+                vec![0.0, 0.0],
+                vec![1.0, 0.0]
             ]
         ).unwrap();
 
-        println!("Asserting that second row can be correctly scaled by 1.5.");
+        assert!(!pivot_not_one.is_reduced_row_echelon(1e-6));
+        assert!(!entry_above_pivot.is_reduced_row_echelon(1e-6));
+        assert!(!pivots_not_moving_right.is_reduced_row_echelon(1e-6));
+        assert!(!zero_row_not_at_bottom.is_reduced_row_echelon(1e-6));
+    }
 
-        test_matrix.scale_row(1, 1.5);
-        assert_eq!(
-            test_matrix.row(1), vec![7.5, 9.0, 10.5, 639.0]
-        );
+    #[test]
+    fn test_orthonormal_null_space() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![3.0, 6.0, 9.0]
+        ]).unwrap();
+
+        let basis = a.orthonormal_null_space();
+
+        println!("Asserting that the kernel of a rank-1 3x3 matrix is two-dimensional.");
+        assert_eq!(basis.len(), 2);
+
+        println!("Asserting that the returned vectors are orthonormal.");
+        for i in 0..basis.len() {
+            for j in 0..basis.len() {
+                let dot: f32 = basis[i].iter().zip(basis[j].iter()).map(|(x, y)| x * y).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-5);
+            }
+        }
+
+        println!("Asserting that the returned vectors lie in the kernel.");
+        for v in basis.iter() {
+            let image = a.apply_to_vector(v).unwrap();
+            assert!(image.iter().all(|x| x.abs() < 1e-5));
+        }
+
+        println!("Asserting that an invertible matrix has a trivial kernel.");
+        assert_eq!(identity_matrix(3).orthonormal_null_space(), Vec::<Vec<f32>>::new());
     }
 
     #[test]
-    fn switch_rows_test() {
-        println!("Creating 4x3 test matrix.");
+    fn test_solve_exact() {
+        let to_rationals = |v: &[i32]| v.iter().map(|&x| Rational::from_integer(x)).collect::<Vec<Rational>>();
 
-        let mut test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 2.0, 3.0],
-                vec![4.0, 426.0, 5.0],
-                vec![426.0, 8.0, 426.0],
-                vec![10.0, 11.0, 12.0]
-            ]
-        ).unwrap();
+        println!("Solving the uniquely solvable 3x3 system exactly.");
 
-        println!("Asserting that rows 1 and 2 can be correctly swapped.");
+        let a = vec![
+            to_rationals(&[2, 1, -1]),
+            to_rationals(&[-3, -1, 2]),
+            to_rationals(&[-2, 1, 2])
+        ];
+
+        assert_eq!(solve_exact(&a, &to_rationals(&[8, -11, -3])), Ok(to_rationals(&[2, 3, -1])));
+
+        println!("Solving a system with a fractional solution.");
+
+        let b = vec![
+            to_rationals(&[2, 1]),
+            to_rationals(&[1, 3])
+        ];
 
-        test_matrix.switch_rows(1, 2);
         assert_eq!(
-            test_matrix,
-            Matrix::new(
-                vec![
-                    vec![1.0, 2.0, 3.0],
-                    vec![426.0, 8.0, 426.0],
-                    vec![4.0, 426.0, 5.0],
-                    vec![10.0, 11.0, 12.0]
-                ]
-            ).unwrap()
-        )
+            solve_exact(&b, &to_rationals(&[1, 1])), 
+            Ok(vec![Rational::new(2, 5).unwrap(), Rational::new(1, 5).unwrap()])
+        );
+
+        println!("Asserting that singular and malformed systems are rejected.");
+
+        let singular = vec![
+            to_rationals(&[1, 1]),
+            to_rationals(&[2, 2])
+        ];
+
+        assert_eq!(solve_exact(&singular, &to_rationals(&[1, 2])), Err(MatrixError::NoUniqueSolutionError));
+        assert_eq!(solve_exact(&singular, &to_rationals(&[1, 3])), Err(MatrixError::NoUniqueSolutionError));
+        assert_eq!(solve_exact(&a, &to_rationals(&[1])), Err(MatrixError::DimensionMismatchError));
     }
 
     #[test]
-    fn add_scalar_multiple_test() {
-        println!("Creating test 3x2 matrix.");
-
-        let mut test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 3.0],
-                vec![0.0, 2.0],
-                vec![4.0, 426.0]
-            ]
-        ).unwrap();
+    fn test_block_diagonal() {
+        let a = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let b = Matrix::new(vec![vec![4.0]]).unwrap();
+        let c = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
 
-        println!("Adding 3.2 times row 0 to row 1.");
+        let d = block_diagonal(&[a.clone(), b.clone(), c.clone()]);
 
-        test_matrix.add_scalar_multiple(1, 3.2, 0);
+        println!("Asserting that the blocks are placed along the diagonal.");
         assert_eq!(
-            test_matrix,    
-            Matrix::new(
-                vec![
-                    vec![1.0, 3.0],
-                    vec![3.2, 11.6],
-                    vec![4.0, 426.0]
-                ]
-            ).unwrap()
+            d, 
+            Matrix::new(vec![
+                vec![2.0, 1.0, 0.0, 0.0, 0.0],
+                vec![1.0, 3.0, 0.0, 0.0, 0.0],
+                vec![0.0, 0.0, 4.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0, 2.0],
+                vec![0.0, 0.0, 0.0, 3.0, 4.0]
+            ]).unwrap()
         );
+
+        println!("Asserting that the determinant is the product of the blocks' determinants.");
+        let product = a.determinant().unwrap() * b.determinant().unwrap() * c.determinant().unwrap();
+        assert!((d.determinant().unwrap() - product).abs() < 1e-4);
+
+        println!("Asserting that rectangular blocks add up their dimensions.");
+        let e = block_diagonal(&[Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap(), b]);
+        assert_eq!(e.num_rows(), 2);
+        assert_eq!(e.num_columns(), 4);
+        assert_eq!(e.row(1), vec![0.0, 0.0, 0.0, 4.0]);
     }
 
     #[test]
-    fn is_zero_row_column_test(){
-        println!("Creating 3x4 test matrix.");
+    fn test_inverse() {
+        let a = Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+
+        println!("Asserting that the product of a matrix and its inverse is the identity.");
+        let product = multiply(&a, &a.inverse().unwrap()).unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product.entry(i, j) - expected).abs() < 1e-5);
+            }
+        }
 
-        let test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 2.0, 426.0, 0.0],
-                vec![0.0, 0.0, 0.0, 0.0],
-                vec![9.0, 10.0, 11.0, 0.0]
-            ]
-        ).unwrap();
+        println!("Asserting that diagonal matrices are inverted although their normalized pivots are not exactly 1.0.");
+        let diagonal = [41.0, 47.0, 55.0, 61.0];
+        let d = Matrix::new((0..4).map(|i| (0..4).map(|j| if i == j { diagonal[i] } else { 0.0 }).collect()).collect()).unwrap();
+        let d_inverse = d.inverse().unwrap();
+        for (i, &d_i) in diagonal.iter().enumerate() {
+            for j in 0..4 {
+                let expected = if i == j { 1.0 / d_i } else { 0.0 };
+                assert!((d_inverse.entry(i, j) - expected).abs() < 1e-5);
+            }
+        }
+        assert!(d.condition_number().is_ok());
 
-        println!("Asserting that zero row is correctly classified.");
+        println!("Asserting that singular and non-square matrices cannot be inverted.");
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(singular.inverse(), Err(MatrixError::SingularMatrixError));
+        assert_eq!(Matrix::new(vec![vec![1.0, 2.0]]).unwrap().inverse(), Err(MatrixError::NotSquareError));
+    }
 
-        assert_eq!(test_matrix.is_zero_row(1), true);
+    #[test]
+    fn test_condition_number() {
+        println!("Asserting that the condition number of the n x n identity is n.");
+        assert!((identity_matrix(1).condition_number().unwrap() - 1.0).abs() < 1e-5);
+        assert!((identity_matrix(3).condition_number().unwrap() - 3.0).abs() < 1e-5);
+
+        println!("Asserting that a nearly singular matrix has a large condition number.");
+        let nearly_singular = Matrix::new(vec![vec![1.0, 1.0], vec![1.0, 1.001]]).unwrap();
+        assert!(nearly_singular.condition_number().unwrap() > 1000.0);
+
+        println!("Asserting that a singular matrix has no condition number.");
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(singular.condition_number(), Err(MatrixError::SingularMatrixError));
+    }
 
-        println!("Asserting that non-zero row is correctly classified.");
+    #[test]
+    fn test_smith_normal_form_diagonal() {
+        println!("Computing the invariant factors of some integer matrices.");
 
-        assert_eq!(test_matrix.is_zero_row(2), false);
+        assert_eq!(smith_normal_form_diagonal(&[vec![2, 4], vec![6, 8]]), vec![2, 4]);
+        assert_eq!(
+            smith_normal_form_diagonal(&[vec![2, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]]), 
+            vec![2, 6, 12]
+        );
 
-        println!("Asserting that zero column is correctly classified.");
+        println!("Computing the invariant factors of rank-deficient and non-square matrices.");
 
-        assert_eq!(test_matrix.is_zero_column(3), true);
+        assert_eq!(smith_normal_form_diagonal(&[vec![1, 2], vec![2, 4]]), vec![1, 0]);
+        assert_eq!(smith_normal_form_diagonal(&[vec![6, 4, 10]]), vec![2]);
+        assert_eq!(smith_normal_form_diagonal(&[vec![0, 0], vec![0, 0]]), vec![0, 0]);
+    }
 
-        println!("Asserting that non-zero column is correctly classified.");
+    #[test]
+    fn test_to_reduced_with_record() {
+        let original = Matrix::new(vec![
+            vec![0.0, 2.0, 1.0, 4.0],
+            vec![1.0, 1.0, 3.0, 2.0],
+            vec![2.0, 4.0, 7.0, 8.0]
+        ]).unwrap();
+
+        let mut reduced = original.clone();
+        let record = reduced.to_reduced_with_record();
+
+        println!("Asserting that the recorded reduction equals to_upper_triangular.");
+        let mut expected = original.clone();
+        expected.to_upper_triangular();
+        assert!(reduced.is_reduced_row_echelon(1e-5));
+
+        println!("Asserting that E * original reproduces the reduced form.");
+        let product = multiply(&record, &original).unwrap();
+        for i in 0..3 {
+            for j in 0..4 {
+                assert!((product.entry(i, j) - reduced.entry(i, j)).abs() < 1e-5);
+                assert!((expected.entry(i, j) - reduced.entry(i, j)).abs() < 1e-5);
+            }
+        }
+    }
 
-        assert_eq!(test_matrix.is_zero_column(0), false);
+    #[test]
+    fn test_safe_indexing() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+
+        println!("Asserting that valid indices yield entries, rows and columns.");
+        assert_eq!(a.get(0, 0), Some(1.0));
+        assert_eq!(a.get(1, 2), Some(6.0));
+        assert_eq!(a.get_row(1), Some(vec![4.0, 5.0, 6.0]));
+        assert_eq!(a.get_column(2), Some(vec![3.0, 6.0]));
+
+        println!("Asserting that out-of-range indices yield None.");
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(a.get(0, 3), None);
+        assert_eq!(a.get_row(2), None);
+        assert_eq!(a.get_column(3), None);
     }
 
     #[test]
-    fn test_first_row_without_zero_at_beginning_from() {
-        println!("Creating 3x3 test matrix.");
+    fn test_bareiss_determinant() {
+        println!("Computing some known determinants of integer matrices.");
 
-        let test_matrix = Matrix::new(
-            vec![
-                vec![1.0, 0.0, 0.0],
-                vec![0.0, 0.0, 0.0],
-                vec![0.0, 1.0, 0.0]
-            ]
-        ).unwrap();
+        assert_eq!(bareiss_determinant(&[vec![2, 4], vec![6, 8]]), -8);
+        assert_eq!(bareiss_determinant(&[vec![0, 1], vec![1, 0]]), -1);
+        assert_eq!(bareiss_determinant(&[vec![1, 2], vec![2, 4]]), 0);
+        assert_eq!(bareiss_determinant(&[vec![426]]), 426);
+        assert_eq!(bareiss_determinant(&[]), 1);
 
-        println!("Searching for non zero elements in different columns.");
+        println!("Asserting that the Bareiss algorithm matches cofactor expansion.");
 
-        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(0, 0), Some(0));
-        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(0, 1), None);
-        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(1, 0), Some(2));
-        assert_eq!(test_matrix.next_row_without_zero_at_beginning_from(2, 0), None);
+        let matrices: Vec<Vec<Vec<i64>>> = vec![
+            vec![vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 2]],
+            vec![vec![0, 2, 1, 3], vec![4, -1, 0, 2], vec![1, 1, 5, -2], vec![3, 0, 2, 1]],
+            vec![vec![0, 0, 1], vec![0, 1, 0], vec![1, 0, 0]]
+        ];
+
+        for m in matrices {
+            let float_matrix = Matrix::new(
+                m.iter().map(|row| row.iter().map(|&x| x as f32).collect()).collect()
+            ).unwrap();
+
+            assert_eq!(bareiss_determinant(&m) as f32, float_matrix.determinant_cofactor().unwrap());
+        }
     }
 
     #[test]
-    fn test_first_non_zero_row_beginning_from() {
-        println!("Creating 5x3 test matrix.");
-
-        let test_matrix = Matrix::new(
+    fn test_principal_minor_sums() {
+        let a = Matrix::new(
             vec![
-                vec![1.0, 0.0, 0.0],
-                vec![0.0, 0.0, 0.0],
-                vec![0.0, 426.0, 0.0],
-                vec![0.0, 0.0, 0.0],
-                vec![0.0, 0.0, 0.0],
+                vec![2.0, 1.0, 0.0],
+                vec![1.0, 3.0, 1.0],
+                vec![4.0, 0.0, -1.0]
             ]
         ).unwrap();
 
-        println!("Searching for next non-zero row from various different starting rows.");
+        let sums = a.principal_minor_sums().unwrap();
+        let chi = a.characteristic_polynomial().unwrap();
 
-        assert_eq!(test_matrix.next_non_zero_row_beginning_from(0), Some(0));
-        assert_eq!(test_matrix.next_non_zero_row_beginning_from(1), Some(2));
-        assert_eq!(test_matrix.next_non_zero_row_beginning_from(2), Some(2));
-        assert_eq!(test_matrix.next_non_zero_row_beginning_from(3), None);
-        assert_eq!(test_matrix.next_non_zero_row_beginning_from(4), None);
+        println!("Asserting that the principal minor sums match the coefficients of the characteristic polynomial.");
+        for k in 1..4 {
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            assert!((sign * sums[k - 1] - chi.coefficient(3 - k) as f32).abs() < 1e-4);
+        }
+
+        println!("Asserting that the first sum is the trace and the last one is the determinant.");
+        assert!((sums[0] - a.trace().unwrap()).abs() < 1e-4);
+        assert!((sums[2] - a.determinant().unwrap()).abs() < 1e-4);
+
+        println!("Asserting that non-square matrices are rejected.");
+        assert_eq!(Matrix::new(vec![vec![1.0, 2.0]]).unwrap().principal_minor_sums(), Err(MatrixError::NotSquareError));
     }
 
     #[test]
-    fn test_to_upper_triangular() {
-        println!("Creating 3x4 test matrix.");
+    fn test_inverse_mod() {
+        let a = vec![vec![2, 3], vec![1, 4]];
+
+        println!("Asserting that the inverse modulo 7 multiplies back to the identity modulo 7.");
+        let a_inverse = inverse_mod(&a, 7).unwrap();
+        for (i, a_row) in a.iter().enumerate() {
+            for j in 0..2 {
+                let entry = a_row.iter().zip(a_inverse.iter()).map(|(a_ik, inverse_row)| a_ik * inverse_row[j]).sum::<i64>() % 7;
+                assert_eq!(entry, if i == j { 1 } else { 0 });
+            }
+        }
 
-        let mut test_matrix1 = Matrix::new(
-            vec![
-                vec![1.0, 1.0, -1.0, 0.0],
-                vec![2.0, 1.0, 0.0, 1.0],
-                vec![3.0, 1.0, 2.0, 0.0]
-            ]
-        ).unwrap();
+        println!("Asserting that matrices singular modulo p and non-prime moduli are rejected.");
+        // determinant 5
+        assert_eq!(inverse_mod(&a, 5), None);
+        assert_eq!(inverse_mod(&a, 6), None);
+        assert_eq!(inverse_mod(&[vec![1, 2]], 7), None);
+
+        println!("Computing the inverse modulo the prime i32::MAX.");
+        let p = i32::MAX as i64;
+        let c = vec![vec![p - 1, 2], vec![3, p - 5]];
+        let c_inverse = inverse_mod(&c, p).unwrap();
+        for (i, c_row) in c.iter().enumerate() {
+            for j in 0..2 {
+                let entry = c_row.iter().zip(c_inverse.iter()).map(|(c_ik, inverse_row)| c_ik * inverse_row[j] % p).sum::<i64>() % p;
+                assert_eq!(entry, if i == j { 1 } else { 0 });
+            }
+        }
+    }
 
-        println!("Assert that upper triangular form of the first test matrix is correctly computed.");
+    #[test]
+    fn test_approx_eq() {
+        let a = Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
 
-        test_matrix1.to_upper_triangular();
-        assert_eq!(
-            test_matrix1,
-            Matrix::new(
-                vec![
-                    vec![1.0, 0.0, 0.0, 3.0],
-                    vec![0.0, 1.0, 0.0, -5.0],
-                    vec![0.0, 0.0, 1.0, -2.0]
-                ]
-            ).unwrap()
-        );
+        println!("Asserting that a matrix times its inverse is approximately the identity.");
+        assert!(multiply(&a, &a.inverse().unwrap()).unwrap().approx_eq(&identity_matrix(2), 1e-5));
+        assert!(!a.approx_eq(&identity_matrix(2), 1e-5));
 
-        println!("Creating 3x5 test matrix.");
+        println!("Asserting that matrices of different dimensions are not approximately equal.");
+        assert!(!identity_matrix(2).approx_eq(&identity_matrix(3), 1e-5));
+        assert!(!identity_matrix(2).approx_eq(&Matrix::new(vec![vec![1.0, 0.0]]).unwrap(), 1e-5));
+    }
 
-        let mut test_matrix2 = Matrix::new(
+    #[test]
+    fn test_is_consistent() {
+        let a = Matrix::new(
             vec![
-                vec![3.0, -3.0, 3.0, 6.0, 3.0],
-                vec![1.0, -1.0, -3.0, 0.0, -8.0],
-                vec![2.0, -2.0, -2.0, 2.0, 5.0]
+                vec![1.0, 2.0],
+                vec![2.0, 4.0]
             ]
         ).unwrap();
 
-        println!("Assert that upper triangular form of the second test matrix is correctly computed.");
+        println!("Asserting that x + 2y = 1, 2x + 4y = 3 is inconsistent.");
+        assert_eq!(is_consistent(&a, &[1.0, 3.0]), Ok(false));
 
-        test_matrix2.to_upper_triangular();
-        assert_eq!(
-            test_matrix2,
-            Matrix::new(
-                vec![
-                    vec![1.0, -1.0, 0.0, 1.5, 0.0],
-                    vec![0.0, 0.0, 1.0, 0.5, 0.0],
-                    vec![0.0, 0.0, 0.0, 0.0, 1.0]
-                ]
-            ).unwrap()
-        );
+        println!("Asserting that x + 2y = 1, 2x + 4y = 2 is solvable.");
+        assert_eq!(is_consistent(&a, &[1.0, 2.0]), Ok(true));
 
-        println!("Creating 4x5 test matrix.");
+        println!("Asserting that a system with invertible coefficient matrix is solvable.");
+        assert_eq!(is_consistent(&identity_matrix(2), &[426.0, 1.0]), Ok(true));
 
-        let mut test_matrix3 = Matrix::new(
+        println!("Asserting that a right-hand side of wrong length is rejected.");
+        assert_eq!(is_consistent(&a, &[1.0]), Err(MatrixError::DimensionMismatchError));
+    }
+
+    #[test]
+    fn test_transpose_gram() {
+        let a = Matrix::new(
             vec![
-                vec![1.0, 1.0, 1.0, 0.0, 3.0],
-                vec![-11.0, 1.0, 9.0, 2.0, -15.0],
-                vec![3.0, 0.0, -3.0, 0.0, 3.0],
-                vec![8.0, 2.0, -4.0, -1.0, 13.0]
+                vec![1.0, 2.0],
+                vec![3.0, -4.0],
+                vec![426.0, 0.5]
             ]
         ).unwrap();
 
-        println!("Assert that upper triangular form of the third test matrix is correctly computed.");
+        println!("Asserting that transposing a 3x2 matrix yields a 2x3 matrix.");
+        let a_transposed = a.transpose();
+        assert_eq!(a_transposed, Matrix::new(vec![vec![1.0, 3.0, 426.0], vec![2.0, -4.0, 0.5]]).unwrap());
+        assert_eq!(a_transposed.transpose(), a);
 
-        test_matrix3.to_upper_triangular();
-        assert_eq!(
-            test_matrix3,
-            Matrix::new(
-                vec![
-                    vec![1.0, 0.0, 0.0, 0.0, 2.0],
-                    vec![0.0, 1.0, 0.0, 0.0, 0.0],
-                    vec![0.0, 0.0, 1.0, 0.0, 1.0],
-                    vec![0.0, 0.0, 0.0, 1.0, -1.0]
-                ]
-            ).unwrap()
-        );
+        println!("Asserting that the Gram matrix equals A^T * A and is symmetric.");
+        let gram = a.gram();
+        assert_eq!((gram.num_rows(), gram.num_columns()), (2, 2));
+        assert!(gram.approx_eq(&multiply(&a_transposed, &a).unwrap(), 1e-3));
+        assert_eq!(gram, gram.transpose());
+    }
 
-        println!("Creating 3x4 test matrix.");
+    #[test]
+    fn test_rank_mod() {
+        println!("Asserting that a matrix of full rank over the rationals has rank 1 modulo 2.");
+        let a = vec![vec![1, 1], vec![1, -1]];
+        assert_eq!(rank_mod(&a, 2), Some(1));
+        assert_eq!(rank_mod(&a, 3), Some(2));
+
+        println!("Computing the rank of a 3x4 matrix modulo 5 whose third row is the sum of the others.");
+        let b = vec![vec![1, 2, 3, 4], vec![0, 1, 426, 2], vec![1, 3, 429, 6]];
+        assert_eq!(rank_mod(&b, 5), Some(2));
+        assert_eq!(rank_mod(&[vec![5, 10], vec![0, 0]], 5), Some(0));
+
+        println!("Asserting that non-prime moduli and non-uniform rows yield None.");
+        assert_eq!(rank_mod(&a, 4), None);
+        assert_eq!(rank_mod(&[vec![1, 2], vec![3]], 5), None);
+
+        println!("Computing the rank modulo the prime i32::MAX.");
+
+        let p = i32::MAX as i64;
+        assert_eq!(rank_mod(&[vec![p - 1, 2], vec![3, p - 5]], p), Some(2));
+        assert_eq!(rank_mod(&[vec![1, 2], vec![p + 2, 4]], p), Some(1));
+    }
 
-        let mut test_matrix4 = Matrix::new(
+    #[test]
+    fn test_permanent() {
+        println!("Computing the permanent of a 3x3 matrix.");
+        let a = Matrix::new(
             vec![
-                vec![3.0, 0.0, 3.0, 0.0],
-                vec![3.0, 1.0, 1.0, 0.0],
-                vec![2.0, 1.0, 0.0, 0.0]
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 9.0]
             ]
         ).unwrap();
 
-        println!("Assert that upper triangular form of the fourth test matrix is correctly computed.");
+        // 1*5*9 + 1*6*8 + 2*4*9 + 2*6*7 + 3*4*8 + 3*5*7 = 45 + 48 + 72 + 84 + 96 + 105
+        assert_eq!(a.permanent(), Ok(450.0));
 
-        test_matrix4.to_upper_triangular();
-        assert_eq!(
-            test_matrix4,
-            Matrix::new(
-                vec![
-                    vec![1.0, 0.0, 1.0, 0.0],
-                    vec![0.0, 1.0, -2.0, 0.0],
-                    vec![0.0, 0.0, 0.0, 0.0]
-                ]
-            ).unwrap()
-        );
+        println!("Asserting that the permanent differs from the (vanishing) determinant.");
+        assert!(a.determinant_cofactor().unwrap() != a.permanent().unwrap());
 
-        println!("Assert idempotency of operation.");
+        println!("Asserting that permutation matrices have permanent 1 and the all-ones 4x4 matrix has permanent 4! = 24.");
+        let permutation_matrix = Matrix::new(vec![vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0], vec![1.0, 0.0, 0.0]]).unwrap();
+        assert_eq!(permutation_matrix.permanent(), Ok(1.0));
+        assert_eq!(identity_matrix(4).permanent(), Ok(1.0));
+        assert_eq!(Matrix::new(vec![vec![1.0; 4]; 4]).unwrap().permanent(), Ok(24.0));
 
-        // at this point, the matrix already is in upper triangular form
-        test_matrix4.to_upper_triangular();
+        println!("Asserting that non-square matrices are rejected.");
+        assert_eq!(Matrix::new(vec![vec![1.0, 2.0]]).unwrap().permanent(), Err(MatrixError::NotSquareError));
+    }
 
-        test_matrix4.to_upper_triangular();
+    #[test]
+    fn test_satisfies_characteristic_polynomial() {
+        println!("Verifying the Cayley-Hamilton theorem for integer 2x2 and 3x3 matrices.");
+        let matrices = [
+            Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap(),
+            Matrix::new(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap(),
+            Matrix::new(vec![vec![2.0, 0.0, 1.0], vec![-1.0, 3.0, 0.0], vec![4.0, 1.0, -2.0]]).unwrap(),
+            Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]]).unwrap(),
+            identity_matrix(3)
+        ];
+        for a in matrices.iter() {
+            assert_eq!(a.satisfies_characteristic_polynomial(1e-3), Ok(true));
+        }
+
+        println!("Asserting that non-square and non-integer matrices are rejected.");
         assert_eq!(
-            test_matrix4,
-            Matrix::new(
-                vec![
-                    vec![1.0, 0.0, 1.0, 0.0],
-                    vec![0.0, 1.0, -2.0, 0.0],
-                    vec![0.0, 0.0, 0.0, 0.0]
-                ]
-            ).unwrap()
+            Matrix::new(vec![vec![1.0, 2.0]]).unwrap().satisfies_characteristic_polynomial(1e-3),
+            Err(MatrixError::NotSquareError)
+        );
+        assert_eq!(
+            Matrix::new(vec![vec![0.5]]).unwrap().satisfies_characteristic_polynomial(1e-3),
+            Err(MatrixError::NonIntegerEntryError)
         );
     }
+
+    #[test]
+    fn test_lu_decompose() {
+        let matrices = [
+            Matrix::new(vec![vec![2.0, 1.0, 1.0], vec![4.0, -6.0, 0.0], vec![-2.0, 7.0, 2.0]]).unwrap(),
+            Matrix::new(vec![vec![0.0, 1.0], vec![426.0, 3.0]]).unwrap(),
+            // singular matrix
+            Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0], vec![1.0, 0.0, 1.0]]).unwrap(),
+            // the factors 1/41 and 1/47 are not exact in floating point arithmetic
+            Matrix::new(vec![vec![41.0, 1.0], vec![1.0, 2.0]]).unwrap(),
+            Matrix::new(vec![vec![47.0, 3.0, 5.0], vec![3.0, 41.0, 7.0], vec![11.0, 13.0, 17.0]]).unwrap(),
+            // singular matrix, whose vanishing pivot is only zero up to rounding errors
+            Matrix::new(vec![vec![0.1, 0.3], vec![0.7, 2.1]]).unwrap()
+        ];
+
+        for a in matrices.iter() {
+            println!("Decomposing a {}x{} matrix.", a.num_rows(), a.num_columns());
+            let (l, u, sigma) = a.lu_decompose().unwrap();
+            let n = a.num_rows();
+
+            println!("Asserting that L is unit lower triangular and U is upper triangular.");
+            for i in 0..n {
+                assert_eq!(l.entry(i, i), 1.0);
+                for j in (i+1)..n {
+                    assert_eq!(l.entry(i, j), 0.0);
+                    assert_eq!(u.entry(j, i), 0.0);
+                }
+            }
+
+            println!("Asserting that permuting the rows of L * U back recovers the matrix.");
+            let lu = multiply(&l, &u).unwrap();
+            let inverse = sigma.inverse();
+            let recovered = Matrix::new((1..(n+1)).map(|i| lu.row(inverse.eval(i).unwrap() - 1)).collect()).unwrap();
+            assert!(recovered.approx_eq(a, 1e-3));
+        }
+
+        println!("Asserting that non-square matrices are rejected.");
+        assert_eq!(Matrix::new(vec![vec![1.0, 2.0]]).unwrap().lu_decompose(), Err(MatrixError::NotSquareError));
+    }
 }
\ No newline at end of file
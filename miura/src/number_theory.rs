@@ -0,0 +1,266 @@
+//! Contains elementary number-theoretic functions on integers.
+
+
+
+use std::ops::{Add, Rem}; // for reductions of wider integer types
+
+/// Computes the greatest common divisor of a and b using the Euclidean algorithm.
+///
+/// The result is always non-negative, 
+/// and the greatest common divisor of 0 and 0 is 0.
+pub fn euclid(a: i32, b: i32) -> i32 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+
+    /*
+    * Invariant: gcd(a, b) stays the same in every iteration,
+    * since every common divisor of a and b also divides a mod b.
+    */
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Computes the greatest common divisor of the unsigned integers a and b using the Euclidean algorithm.
+///
+/// Behaves like euclid, but works for numbers beyond the i32 range, e.g. orders of permutations.
+pub fn euclid_usize(a: usize, b: usize) -> usize {
+    let mut a = a;
+    let mut b = b;
+
+    // same invariant as in euclid
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Reduces x modulo q to the least non-negative residue, i.e. the representative in {0, ..., q-1}.
+///
+/// Unlike the % operator, whose result has the sign of x, 
+/// the result is non-negative for negative x as well (for positive q).
+/// No intermediate result exceeds q, so this works for moduli up to i32::MAX.
+pub fn reduce_mod(x: i32, q: i32) -> i32 {
+    x.rem_euclid(q)
+}
+
+/// Reduces x modulo q to the least non-negative residue like reduce_mod, but for wider integer types like i64 and i128.
+///
+/// This is meant for intermediate results of modular arithmetic, e.g. products of two residues,
+/// which are computed in the wider type to prevent overflows. 
+/// As long as q fits into an i32, x % q + q does not overflow.
+pub fn reduce_mod_wide<T: Copy + Add<Output = T> + Rem<Output = T>>(x: T, q: T) -> T {
+    ((x % q) + q) % q
+}
+
+/// Computes the product a * b modulo q and returns the representative in {0, ..., q-1}.
+///
+/// The product is computed with 64 bits, so it does not overflow even if a and b are close to i32::MAX.
+pub fn mul_mod(a: i32, b: i32, q: i32) -> i32 {
+    reduce_mod_wide(a as i64 * b as i64, q as i64) as i32
+}
+
+/// Computes the multiplicative inverse of a in the remainder class ring Z/qZ,
+/// i.e. the number b in {0, ..., q-1} with a * b = 1 mod q.
+///
+/// The inverse exists if and only if a and q are coprime,
+/// otherwise the None variant is returned. 
+/// It is computed using the extended Euclidean algorithm.
+pub fn mod_inverse(a: i32, q: i32) -> Option<i32> {
+    /*
+    * The extended Euclidean algorithm maintains the invariant
+    * old_s * a = old_r mod q and s * a = r mod q.
+    * Once r reaches 0, old_r is gcd(a, q).
+    */
+    let (mut old_r, mut r) = (reduce_mod(a, q), q);
+    let (mut old_s, mut s) = (1, 0);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    if old_r != 1 {
+        return None;
+    }
+
+    Some(reduce_mod(old_s, q))
+}
+
+/// Computes base^exponent modulo q using square-and-multiply, 
+/// so only O(log exponent) multiplications are needed.
+///
+/// The result is from {0, ..., q-1}, intermediate results are computed with 64 bits to prevent overflows.
+/// For exponent 0, the result is 1 modulo q (also for base 0).
+pub fn mod_pow(base: i32, exponent: u64, q: i32) -> i32 {
+    let q = q as i64;
+    let mut result = 1 % q;
+    let mut power = reduce_mod(base, q as i32) as i64;
+    let mut e = exponent;
+
+    // invariant: result * power^e stays base^exponent modulo q
+    while e > 0 {
+        if e % 2 == 1 {
+            result = (result * power) % q;
+        }
+        power = (power * power) % q;
+        e /= 2;
+    }
+
+    result as i32
+}
+
+/// Determines whether the passed integer is a prime number
+/// by trial division by all numbers up to its square root.
+pub fn is_prime(n: i32) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    // d <= n / d is equivalent to d * d <= n, but cannot overflow
+    let mut d = 2;
+    while d <= n / d {
+        if n % d == 0 {
+            return false;
+        }
+        d += 1;
+    }
+
+    true
+}
+
+
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::number_theory::*;
+
+    #[test]
+    fn euclid_test() {
+        println!("Computing the gcd of two positive integers.");
+
+        assert_eq!(euclid(426, 18), 6);
+        assert_eq!(euclid(18, 426), 6);
+
+        println!("Computing the gcd of coprime integers.");
+
+        assert_eq!(euclid(17, 426), 1);
+
+        println!("Computing the gcd of negative integers.");
+
+        assert_eq!(euclid(-426, 18), 6);
+        assert_eq!(euclid(-426, -18), 6);
+
+        println!("Computing the gcd involving 0.");
+
+        assert_eq!(euclid(426, 0), 426);
+        assert_eq!(euclid(0, 0), 0);
+
+        println!("Computing the gcd of unsigned integers beyond the i32 range.");
+
+        assert_eq!(euclid_usize(304250263527210, 31), 31);
+        assert_eq!(euclid_usize(1 << 40, 6 << 35), 1 << 36);
+        assert_eq!(euclid_usize(426, 0), 426);
+    }
+
+    #[test]
+    fn mod_inverse_test() {
+        println!("Computing inverses modulo a prime.");
+
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(1, 7), Some(1));
+        assert_eq!(mod_inverse(-1, 7), Some(6));
+
+        println!("Asserting that non-invertible elements have no inverse.");
+
+        assert_eq!(mod_inverse(4, 426), None);
+        assert_eq!(mod_inverse(0, 7), None);
+    }
+
+    #[test]
+    fn is_prime_test() {
+        println!("Testing primes and non-primes.");
+
+        assert!(is_prime(2));
+        assert!(is_prime(5));
+        assert!(is_prime(7919));
+        assert!(!is_prime(1));
+        assert!(!is_prime(0));
+        assert!(!is_prime(-5));
+        assert!(!is_prime(426));
+
+        println!("Testing numbers close to i32::MAX.");
+
+        assert!(is_prime(i32::MAX));
+        assert!(!is_prime(i32::MAX - 1));
+        assert!(is_prime(2_147_483_629));
+    }
+
+    #[test]
+    fn test_reduce_mod() {
+        println!("Reducing non-negative and negative numbers modulo 5.");
+
+        assert_eq!(reduce_mod(426, 5), 1);
+        assert_eq!(reduce_mod(0, 5), 0);
+        assert_eq!(reduce_mod(-1, 5), 4);
+        assert_eq!(reduce_mod(-10, 5), 0);
+        assert_eq!(reduce_mod(-426, 5), 4);
+
+        println!("Reducing numbers modulo moduli close to i32::MAX.");
+
+        assert_eq!(reduce_mod(-1, i32::MAX), i32::MAX - 1);
+        assert_eq!(reduce_mod(-1, i32::MAX - 1), i32::MAX - 2);
+        assert_eq!(reduce_mod(i32::MIN, i32::MAX), i32::MAX - 1);
+        assert_eq!(reduce_mod(i32::MAX, i32::MAX), 0);
+        assert_eq!(reduce_mod(i32::MAX - 1, i32::MAX), i32::MAX - 1);
+
+        println!("Reducing 64 and 128 bit intermediate results modulo moduli fitting into an i32.");
+
+        let q = i32::MAX as i64;
+        assert_eq!(reduce_mod_wide(-1, 5), 4);
+        assert_eq!(reduce_mod_wide((q - 1) * (q - 1), q), 1);
+        assert_eq!(reduce_mod_wide(-(q - 1) * (q - 2), q), q - 2);
+        assert_eq!(reduce_mod_wide(-(1i128 << 100), 426), 122);
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        println!("Multiplying residues modulo 7.");
+
+        assert_eq!(mul_mod(3, 5, 7), 1);
+        assert_eq!(mul_mod(-3, 5, 7), 6);
+        assert_eq!(mul_mod(0, 426, 7), 0);
+
+        println!("Multiplying residues whose product exceeds i32::MAX.");
+
+        assert_eq!(mul_mod(60000, 60000, 65537), 52590);
+        assert_eq!(mul_mod(i32::MAX - 1, i32::MAX - 1, i32::MAX), 1);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        println!("Computing powers modulo 7.");
+
+        assert_eq!(mod_pow(3, 4, 7), 4);
+        assert_eq!(mod_pow(-2, 3, 7), 6);
+        assert_eq!(mod_pow(426, 0, 7), 1);
+        assert_eq!(mod_pow(0, 0, 7), 1);
+
+        println!("Asserting Fermat's little theorem for a large exponent.");
+
+        assert_eq!(mod_pow(426, 1_000_000_006, 1_000_000_007), 1);
+    }
+}
\ No newline at end of file
@@ -4,7 +4,11 @@
 
 
 use crate::vec_helper::check_unique_in_1_to_n;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::cmp::max;
+use crate::sorting::count_inversions; // for computing the sign in O(n log n)
+use crate::number_theory::euclid_usize; // for computing the order as least common multiple
+use rand::Rng; // for random injections
 
 /// A struct that models a permutation from some symmetric group S_n,
 /// i.e. a bijective mapping from {1, ..., n} to {1, ..., n}.
@@ -41,13 +45,19 @@ impl Permutation {
         
         /*
         * Iterate over vector and assure that
-        * (i)   all numbers occur at most once
-        * (ii)  all occuring numbers are in {1, ..., n}
+        * (i)   all occuring numbers are in {1, ..., n}
+        * (ii)  all numbers occur at most once
         *
-        * (i) ensures injectivity of the mapping described by the vector
+        * (i) is checked separately first in order to report out-of-range images
+        * with a more specific error than non-bijectivity.
+        *
+        * (ii) ensures injectivity of the mapping described by the vector
         * and since the domain and codomain of the mapping have equal size,
         * this already concludes surjectivity and thus bijectivity.
         */
+        if vec.iter().any(|&x| x < 1 || x > n) {
+            return Err(PermutationError::ImageOutOfRangeError);
+        }
         if !check_unique_in_1_to_n(&vec, n) {
             return Err(PermutationError::NotBijectiveError);
         }
@@ -81,33 +91,95 @@ impl Permutation {
         }
     }
 
+    /// Returns the images of the permutation as a lookup table 
+    /// that can be indexed directly without the range check of eval.
+    ///
+    /// Note that the table is 0-indexed while the permutation operates on {1, ..., n}:
+    /// table[i] is sigma(i+1), the images themselves are still from {1, ..., n}.
+    pub fn as_lookup(self: &Self) -> Vec<usize> {
+        self.images.clone()
+    }
+
+    /// Returns the fixed points of the permutation in ascending order,
+    /// i.e. the numbers i with sigma(i) = i.
+    pub fn fixed_points(self: &Self) -> Vec<usize> {
+        (1..(self.n()+1)).filter(|&i| self.images[i - 1] == i).collect()
+    }
+
+    /// Returns the support of the permutation in ascending order,
+    /// i.e. the numbers i with sigma(i) != i (the complement of the fixed points).
+    ///
+    /// The identity has empty support.
+    pub fn support(self: &Self) -> Vec<usize> {
+        (1..(self.n()+1)).filter(|&i| self.images[i - 1] != i).collect()
+    }
+
+    /// Returns the size of the support of the permutation,
+    /// i.e. the number of elements that are not fixed points.
+    pub fn support_size(self: &Self) -> usize {
+        (1..(self.n()+1)).filter(|&i| self.images[i - 1] != i).count()
+    }
+
+    /// Computes the permutation induced on the unordered pairs {i, j} of distinct numbers from {1, ..., n},
+    /// which maps {i, j} to {sigma(i), sigma(j)} (the action of S_n on 2-subsets).
+    ///
+    /// The pairs are numbered in lexicographic order {1, 2}, {1, 3}, ..., {1, n}, {2, 3}, ..., {n-1, n},
+    /// so the result lives in S_(n choose 2). 
+    /// For n = 1, there are no pairs, and the identity of S_1 is returned 
+    /// (just like for n = 2, where the single pair is always fixed).
+    pub fn act_on_pairs(self: &Self) -> Permutation {
+        let n = self.n();
+
+        // there are no pairs, but this library has no permutations of the empty set
+        if n < 2 {
+            return Permutation {
+                images: vec![1]
+            };
+        }
+
+        // number of the pair {i, j} with i < j in lexicographic order, starting with 1
+        let pair_number = |i: usize, j: usize| (i - 1) * n - (i - 1) * i / 2 + (j - i);
+
+        let mut images = vec![];
+        for i in 1..(n+1) {
+            for j in (i+1)..(n+1) {
+                let (a, b) = (self.images[i - 1], self.images[j - 1]);
+                images.push(if a < b { pair_number(a, b) } else { pair_number(b, a) });
+            }
+        }
+
+        /*
+        * No need for the bijectivity check of the constructor here:
+        * sigma maps distinct pairs to distinct pairs since it is injective.
+        */
+        Permutation {
+            images
+        }
+    }
+
     /// Computes the inverse of the permutation.
+    ///
+    /// Runs in linear time, since sigma(i) = j means that the inverse maps j to i,
+    /// so every image can directly be placed at its position in the inverse's image vector.
     pub fn inverse(self: &Self) -> Permutation {
-        let mut inverse_images = Vec::<usize>::new();
         let n = self.images.len();
+        let mut inverse_images = vec![0; n];
         
-        // compute image under inverse separately for every i in {1, ..., n}
-        for i in 1..(n+1) {
+        for i in 0..n {
             /*
-            * Find element in {1, ..., n} that maps to i 
-            * by looking for index j in the image vector that stores i.
+            * Permutations in S_n map from and to the set {1, ..., n}
+            * but the indices of a vector of length n range from 0 to n-1.
             */
-            for j in 0..n {
-                if self.images[j] == i {
-                    /*
-                    * Permutations in S_n map from and to the set {1, ..., n}
-                    * but the indices of a vector of length n range from 0 to n-1.
-                    */
-                    inverse_images.push(j+1);
-
-                    break; // can stop looking once we found the right j
-                }
-            }
+            inverse_images[self.images[i] - 1] = i + 1;
         }
 
-        Permutation::new(
-            inverse_images
-        ).unwrap() // if a mapping is a permutation then so is its inverse -> always Ok-variant
+        /*
+        * No need for the bijectivity check of the constructor here:
+        * if a mapping is a permutation then so is its inverse.
+        */
+        Permutation {
+            images: inverse_images
+        }
     }
 
     /// Computes the sign of the permutation sigma 
@@ -130,78 +202,306 @@ impl Permutation {
         if inversions % 2 == 0 { 1 } else { -1 }
     }
 
+    /// Computes the sign of the permutation like sign does,
+    /// but counts the inversions in O(n log n) time using merge sort 
+    /// instead of checking all pairs of numbers.
+    pub fn sign_fast(self: &Self) -> i32 {
+        let images = self.images.iter().map(|&x| x as u32).collect::<Vec<u32>>();
+
+        if count_inversions(&images).is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    /// Conjugates the permutation by the shift-th power of the n-cycle (1 2 ... n),
+    /// i.e. relabels the set {1, ..., n} cyclically by mapping every i to i + shift (modulo n).
+    ///
+    /// For instance, rotating (1 2) in S_4 by 1 yields (2 3).
+    pub fn conjugate_by_rotation(self: &Self, shift: usize) -> Permutation {
+        let n = self.n();
+
+        // (1 2 ... n)^shift maps i to i + shift, wrapping around behind n
+        let rotation = Permutation {
+            images: (1..(n+1)).map(|i| (i - 1 + shift) % n + 1).collect()
+        };
+
+        // both permutations operate on {1, ..., n}, so the conjugation cannot fail
+        conjugate(self, &rotation).unwrap()
+    }
+
+    /// Returns the composition of the permutation sigma with the passed cycle c,
+    /// i.e. sigma after c where c is interpreted as a permutation in the same S_n.
+    /// So a product of cycles c_1 ... c_k can be built step by step starting with the identity.
+    ///
+    /// If the cycle contains numbers outside {1, ..., n}, it does not belong to S_n 
+    /// and an error variant is returned.
+    pub fn apply_cycle(self: &Self, cycle: &Cycle) -> Result<Permutation, PermutationError> {
+        let n = self.n();
+
+        if cycle.elements.iter().any(|&i| i > n) {
+            return Err(PermutationError::DomainRangeSizeMismatchError);
+        }
+
+        // the cycle maps each of its elements to its successor and fixes everything else
+        let mut cycle_images = (1..(n+1)).collect::<Vec<usize>>();
+        let r = cycle.len();
+        for k in 0..r {
+            cycle_images[cycle.elements[k] - 1] = cycle.elements[(k + 1) % r];
+        }
+
+        compose(self, &Permutation { images: cycle_images })
+    }
+
     /// Computes the cycle form of some permutation sigma from its table form.
     /// So instead of a vector of images, the permutation is represented as a vector of Cycles,
     /// where each element from the set {1, ..., n} appears in exactly one cycle.
     pub fn to_cycle_form(self: &Self) -> Vec<Cycle> {
-        // store set size for readability
-        let n = self.n();
-        
-        // create new empty vector to store the computed cycles
-        let mut cycles = Vec::<Cycle>::new();
-        
-        // create hash set that keeps track of the numbers that already are in a cycle
-        let mut nums_in_cycles = HashSet::<usize>::new();
+        self.cycles().collect()
+    }
 
-        for i in 1..(n+1) {
-            /*
-            * If number i is not already contained in a cycle:
-            * compute the cycle that starts with i.
-            */
-            if !nums_in_cycles.contains(&i) {
-                /*
-                * Create vector that contains the elements of the currently computed cycle.
-                * Initially, it only contains i.
-                */
-                let mut current_cycle_elements = vec![i];
-                
-                // mark i as being contained in a cycle
-                nums_in_cycles.insert(i);
-
-                // compute sigma(i)
-                let mut current = self.eval(i).unwrap();
-
-                /*
-                * Until i is reached again: 
-                * iteratively add the current number to the currently computed cycle,
-                * mark it as being contained in a cycle
-                * and evaluate sigma for it.
-                * Once i is reached again, the cycle is complete.
-                */
-                while current != i {
-                    current_cycle_elements.push(current);
-
-                    nums_in_cycles.insert(current);
-
-                    current = self.eval(current).unwrap();
+    /// Computes the cycle form of the inverse permutation without constructing the inverse,
+    /// since the inverse traverses every cycle backwards.
+    ///
+    /// Every cycle keeps its first element while the remaining elements are reversed,
+    /// e.g. (1 2 3) becomes (1 3 2).
+    /// So the result coincides with the cycle form of the inverse (including the order of the cycles).
+    pub fn inverse_cycle_form(self: &Self) -> Vec<Cycle> {
+        self.cycles()
+            .map(|cycle| {
+                let mut elements = cycle.elements;
+                if elements.len() > 1 {
+                    elements[1..].reverse();
                 }
+                Cycle { elements }
+            })
+            .collect()
+    }
+
+    /// Computes the cycle form of the permutation without the length-1 cycles, 
+    /// i.e. only the cycles of the elements that are not fixed points.
+    ///
+    /// The reduced cycle form of the identity is empty.
+    pub fn to_cycle_form_reduced(self: &Self) -> Vec<Cycle> {
+        self.cycles().filter(|c| c.len() > 1).collect()
+    }
+
+    /// Returns an iterator over the cycles of the permutation,
+    /// in the same order as they appear in the cycle form.
+    ///
+    /// The cycles are computed lazily one at a time when the iterator is advanced,
+    /// so unlike with to_cycle_form, not all cycles have to be kept in memory at once.
+    pub fn cycles(self: &Self) -> impl Iterator<Item = Cycle> + '_ {
+        CycleIterator {
+            sigma: self,
+            next_start: 1,
+            nums_in_cycles: HashSet::<usize>::new()
+        }
+    }
 
-                // add completed cycle to the vector of cycles.
-                cycles.push(Cycle::new(current_cycle_elements, n).unwrap());
+    /// Computes the number of cycles of the permutation (including fixed points as length-1 cycles)
+    /// and a histogram of the cycle lengths, 
+    /// i.e. a map from every cycle length to the number of cycles with that length.
+    ///
+    /// The cycles are traversed in a single pass without constructing Cycle instances.
+    pub fn cycle_statistics(self: &Self) -> (usize, HashMap<usize, usize>) {
+        let n = self.n();
+        let mut visited = vec![false; n];
+        let mut num_cycles = 0;
+        let mut histogram = HashMap::<usize, usize>::new();
+
+        for start in 1..(n+1) {
+            if visited[start - 1] {
+                continue;
+            }
+
+            // follow the cycle starting at start until it closes
+            let mut length = 0;
+            let mut current = start;
+            while !visited[current - 1] {
+                visited[current - 1] = true;
+                current = self.images[current - 1];
+                length += 1;
+            }
+
+            num_cycles += 1;
+            *histogram.entry(length).or_insert(0) += 1;
+        }
+
+        (num_cycles, histogram)
+    }
+
+    /// Computes the order of the permutation, 
+    /// i.e. the smallest k >= 1 such that sigma^k is the identity.
+    ///
+    /// This is the least common multiple of the lengths of the cycles of the permutation.
+    pub fn order(self: &Self) -> usize {
+        self.cycles().fold(1, |acc, cycle| {
+            let length = cycle.len();
+            acc / euclid_usize(acc, length) * length
+        })
+    }
+
+    /// Computes the exponent-th power of the permutation,
+    /// i.e. the permutation applied exponent times (the 0-th power is the identity).
+    ///
+    /// Every element of a cycle of length k is mapped to the element exponent steps further in the cycle,
+    /// so only the remainder of exponent modulo k matters and the power is computed in linear time.
+    pub fn pow(self: &Self, exponent: usize) -> Permutation {
+        let mut images = vec![0; self.n()];
+
+        for cycle in self.cycles() {
+            let k = cycle.len();
+            for (position, &element) in cycle.elements.iter().enumerate() {
+                images[element - 1] = cycle.elements[(position + exponent) % k];
             }
         }
 
-        // vector of all cycles of the permutation is returned
-        cycles
+        Permutation {
+            images
+        }
+    }
+
+    /// Returns the cyclic subgroup generated by the permutation,
+    /// i.e. its distinct powers sigma^0, sigma^1, ..., sigma^(order-1) in this order.
+    ///
+    /// The identity generates the subgroup containing only the identity.
+    pub fn cyclic_subgroup(self: &Self) -> Vec<Permutation> {
+        (0..self.order()).map(|k| self.pow(k)).collect()
+    }
+
+    /// Decomposes the permutation into a sequence of transpositions,
+    /// returned as pairs (i, j) of the two numbers swapped by the respective transposition.
+    ///
+    /// For the returned sequence t_1, ..., t_m, the permutation is the composition
+    /// t_1 after t_2 after ... after t_m, i.e. t_m is applied first.
+    ///
+    /// The decomposition is derived from the cycle form:
+    /// a cycle (a_1 a_2 ... a_k) is the composition (a_1 a_k) after (a_1 a_(k-1)) after ... after (a_1 a_2),
+    /// so a k-cycle contributes k-1 transpositions. 
+    /// Since disjoint cycles commute, the transpositions of the single cycles can simply be concatenated.
+    /// The sign of the permutation is -1 if and only if the number of transpositions is odd.
+    /// The identity yields an empty sequence.
+    pub fn to_transpositions(self: &Self) -> Vec<(usize, usize)> {
+        let mut result = Vec::<(usize, usize)>::new();
+
+        for cycle in self.to_cycle_form() {
+            let first = cycle.elements[0];
+
+            // length-1 cycles contribute no transpositions
+            for k in (1..cycle.len()).rev() {
+                result.push((first, cycle.elements[k]));
+            }
+        }
+
+        result
     }
 
     /// Computes a string representation of this permutation using its cycle form.
     /// I.e. the result looks like "(1 5 4)(2 6)".
     pub fn to_string(self: &Self) -> String {
-        // compute cycle form
-        let cycle_form = self.to_cycle_form();
-
-        // filter length-1 cycles and retain vector of remaining ones
-        let filtered_cycle_form = cycle_form.iter().filter(|c| c.len() > 1);
+        // compute cycle form without length-1 cycles
+        let reduced_cycle_form = self.to_cycle_form_reduced();
 
         // concatenate string representations of remaining cycles
         let mut result = String::new();
-        for cycle in filtered_cycle_form {
+        for cycle in reduced_cycle_form {
             result.push_str(&(cycle.to_string()));
         }
 
         result
     }
+
+    /// Computes a canonical string representation of this permutation in cycle notation,
+    /// where every cycle starts with its smallest element, the cycles are ordered by their smallest elements
+    /// and fixed points are omitted, e.g. "(1 5 4)(2 6)".
+    ///
+    /// Since this representation is unique, two permutations on the same set are equal 
+    /// iff their canonical strings are equal. 
+    /// The identity is represented by the empty cycle "()".
+    pub fn to_canonical_string(self: &Self) -> String {
+        let mut cycles = self.to_cycle_form_reduced().into_iter()
+            .map(|cycle| {
+                // rotate the cycle such that it starts with its smallest element
+                let mut elements = cycle.elements;
+                let min_position = (0..elements.len()).min_by_key(|&k| elements[k]).unwrap(); // cycles in reduced form are non-empty
+                elements.rotate_left(min_position);
+                elements
+            })
+            .collect::<Vec<Vec<usize>>>();
+        cycles.sort_by_key(|elements| elements[0]);
+
+        if cycles.is_empty() {
+            return String::from("()");
+        }
+
+        let mut result = String::new();
+        for elements in cycles {
+            result.push_str(&(Cycle { elements }.to_string()));
+        }
+
+        result
+    }
+}
+
+
+
+/// Iterator that lazily computes the cycles of a permutation,
+/// created by the cycles method of a permutation.
+struct CycleIterator<'a> {
+    /// The permutation whose cycles are computed.
+    sigma: &'a Permutation,
+    /// The number from which the search for the start of the next cycle begins.
+    next_start: usize,
+    /// Keeps track of the numbers that already are in a cycle.
+    nums_in_cycles: HashSet<usize>
+}
+
+impl<'a> Iterator for CycleIterator<'a> {
+    type Item = Cycle;
+
+    fn next(self: &mut Self) -> Option<Cycle> {
+        // store set size for readability
+        let n = self.sigma.n();
+
+        // skip numbers that are already contained in a cycle
+        while self.next_start <= n && self.nums_in_cycles.contains(&self.next_start) {
+            self.next_start += 1;
+        }
+
+        // all numbers are contained in a cycle: no cycles left
+        if self.next_start > n {
+            return None;
+        }
+
+        let i = self.next_start;
+
+        /*
+        * Create vector that contains the elements of the currently computed cycle.
+        * Initially, it only contains i.
+        */
+        let mut current_cycle_elements = vec![i];
+        
+        // mark i as being contained in a cycle
+        self.nums_in_cycles.insert(i);
+
+        // compute sigma(i)
+        let mut current = self.sigma.eval(i).unwrap();
+
+        /*
+        * Until i is reached again: 
+        * iteratively add the current number to the currently computed cycle,
+        * mark it as being contained in a cycle
+        * and evaluate sigma for it.
+        * Once i is reached again, the cycle is complete.
+        */
+        while current != i {
+            current_cycle_elements.push(current);
+
+            self.nums_in_cycles.insert(current);
+
+            current = self.sigma.eval(current).unwrap();
+        }
+
+        Some(Cycle::new(current_cycle_elements, n).unwrap())
+    }
 }
 
 
@@ -267,6 +567,57 @@ pub fn identity(n: usize) -> Result<Permutation, PermutationError> {
     )
 }
 
+/// Determines whether the passed vector defines a bijective mapping on {1, ..., n}
+/// (where n is the length of the vector), i.e. whether Permutation::new would accept it.
+///
+/// Unlike Permutation::new, no permutation is constructed, which makes this a cheap predicate for filtering.
+/// The empty vector does not define a permutation.
+pub fn is_valid_permutation(vec: &[usize]) -> bool {
+    !vec.is_empty() && check_unique_in_1_to_n(vec, vec.len())
+}
+
+/// Parses a permutation from its table form (one-line notation), 
+/// i.e. a whitespace- or comma-separated list of its images like "3 1 2" or "3, 1, 2".
+///
+/// If some token is not a non-negative integer, a parse error containing the token is returned.
+/// Otherwise, the images are passed to Permutation::new, so its errors are returned for invalid image vectors.
+pub fn parse_one_line(s: &str) -> Result<Permutation, PermutationError> {
+    let mut images = vec![];
+
+    for token in s.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()) {
+        match token.parse::<usize>() {
+            Ok(image) => images.push(image),
+            Err(_) => return Err(PermutationError::ParseError(token.to_string()))
+        }
+    }
+
+    Permutation::new(images)
+}
+
+/// Chooses a uniformly random injective map from {1, ..., domain} into {1, ..., codomain}
+/// and returns its vector of images, i.e. the k-th entry is the image of k.
+///
+/// Uses a partial Fisher-Yates shuffle of (1, ..., codomain) that stops after the first domain positions.
+/// So for domain = codomain, the result is the image vector of a uniformly random permutation.
+///
+/// If the domain is larger than the codomain, no injective map exists and an error variant is returned.
+pub fn random_injection(domain: usize, codomain: usize, rng: &mut impl Rng) -> Result<Vec<usize>, PermutationError> {
+    if domain > codomain {
+        return Err(PermutationError::NoInjectionError);
+    }
+
+    let mut values = (1..(codomain+1)).collect::<Vec<usize>>();
+
+    // position k receives a uniformly random one of the values not chosen yet
+    for k in 0..domain {
+        let chosen = rng.gen_range(k..codomain);
+        values.swap(k, chosen);
+    }
+
+    values.truncate(domain);
+    Ok(values)
+}
+
 /// Creates a permutation in S_n that swaps the passed i and j
 /// and otherwise behaves like the identity.
 pub fn transposition(n: usize, i: usize, j: usize) -> Result<Permutation, PermutationError> {
@@ -288,6 +639,56 @@ pub fn transposition(n: usize, i: usize, j: usize) -> Result<Permutation, Permut
     }
 }
 
+/// Returns all permutations in S_n that consist of a single cycle of the passed length
+/// (with all other elements being fixed), of which there are n!/((n-length)! * length).
+///
+/// Since every 1-cycle is the identity, only the identity is returned for length 1.
+/// For length 0, n = 0 or length greater than n, the empty vector is returned.
+pub fn cycles_of_length(n: usize, length: usize) -> Vec<Permutation> {
+    if n == 0 || length == 0 || length > n {
+        return vec![];
+    }
+    if length == 1 {
+        return vec![identity(n).unwrap()];
+    }
+
+    let mut result = vec![];
+
+    // each cycle is written uniquely with its smallest element first,
+    // so we fix the first element and extend it by all arrangements of larger elements
+    for first in 1..(n+1) {
+        let mut elements = vec![first];
+        extend_cycles(n, length, &mut elements, &mut result);
+    }
+
+    result
+}
+
+/* 
+ * Recursively extends the partial cycle (whose first element is its smallest one)
+ * by elements larger than the first one until it has the passed length 
+ * and collects the resulting permutations.
+ */
+fn extend_cycles(n: usize, length: usize, elements: &mut Vec<usize>, result: &mut Vec<Permutation>) {
+    if elements.len() == length {
+        // build the permutation mapping every element to its successor in the cycle
+        let mut images = (1..(n+1)).collect::<Vec<usize>>();
+        for k in 0..length {
+            images[elements[k] - 1] = elements[(k + 1) % length];
+        }
+        result.push(Permutation::new(images).unwrap());
+        return;
+    }
+
+    for next in (elements[0] + 1)..(n+1) {
+        if !elements.contains(&next) {
+            elements.push(next);
+            extend_cycles(n, length, elements, result);
+            elements.pop();
+        }
+    }
+}
+
 /// Creates the composition sigma after tau of the two passed permutations sigma and tau. 
 pub fn compose(sigma: &Permutation, tau: &Permutation) -> Result<Permutation, PermutationError> {
     // compute size of set that sigma operates on
@@ -313,6 +714,31 @@ pub fn compose(sigma: &Permutation, tau: &Permutation) -> Result<Permutation, Pe
     )
 }
 
+/// Creates the composition sigma after tau of the two passed permutations sigma and tau,
+/// even if they are from different symmetric groups S_m and S_n.
+///
+/// The permutation from the smaller group is embedded into the larger one
+/// by letting it fix all additional points, e.g. a permutation from S_4 is treated as one from S_6 that fixes 5 and 6.
+/// So unlike compose, the result may lie in a different symmetric group than one of the passed permutations.
+pub fn compose_padded(sigma: &Permutation, tau: &Permutation) -> Permutation {
+    let n = max(sigma.n(), tau.n());
+
+    // evaluates a permutation embedded into S_n
+    let eval_padded = |rho: &Permutation, x: usize| if x <= rho.n() { rho.images[x - 1] } else { x };
+
+    Permutation {
+        images: (1..(n+1)).map(|x| eval_padded(sigma, eval_padded(tau, x))).collect()
+    }
+}
+
+/// Determines whether the two passed permutations commute, 
+/// i.e. whether sigma after tau equals tau after sigma.
+///
+/// If the permutations do not operate on sets of the same size, an error is returned.
+pub fn commutes(sigma: &Permutation, tau: &Permutation) -> Result<bool, PermutationError> {
+    Ok(compose(sigma, tau)? == compose(tau, sigma)?)
+}
+
 /// Returns the composition tau after sigma after inverse(tau).
 pub fn conjugate(sigma: &Permutation, tau: &Permutation) -> Result<Permutation, PermutationError> {
     compose(
@@ -321,6 +747,44 @@ pub fn conjugate(sigma: &Permutation, tau: &Permutation) -> Result<Permutation,
     )
 }
 
+/// Computes all elements of the subgroup of S_n generated by the passed permutations,
+/// i.e. the closure of the generators under composition, starting with the identity.
+///
+/// The elements are found breadth-first: every newly found element is composed with all generators,
+/// where the set of found elements is keyed on the image vectors.
+/// Since S_n is finite, it suffices to close under composition (inverses are powers).
+///
+/// If no generators are passed, n is unknown and an error variant is returned.
+/// This is also the case if the generators do not all belong to the same S_n.
+pub fn generated_subgroup(generators: &[Permutation]) -> Result<Vec<Permutation>, PermutationError> {
+    if generators.is_empty() {
+        return Err(PermutationError::EmptyImageVectorError);
+    }
+
+    let n = generators[0].n();
+    if generators.iter().any(|g| g.n() != n) {
+        return Err(PermutationError::DomainRangeSizeMismatchError);
+    }
+
+    let mut result = vec![identity(n)?];
+    let mut found = HashSet::<Vec<usize>>::new();
+    found.insert(result[0].as_lookup());
+
+    // result doubles as the queue: elements from index next on still have to be composed with the generators
+    let mut next = 0;
+    while next < result.len() {
+        for g in generators.iter() {
+            let product = compose(g, &result[next])?;
+            if found.insert(product.as_lookup()) {
+                result.push(product);
+            }
+        }
+        next += 1;
+    }
+
+    Ok(result)
+}
+
 /// A type that models all kinds of errors
 /// that can occur when working with permutations.
 #[derive(Debug, PartialEq)]
@@ -340,7 +804,12 @@ pub enum PermutationError {
     /// Occurs when attempting to create a permutation from an empty vector of images.
     EmptyImageVectorError,
     /// Occurs when attempting to compose two permutations from different symmetric groups.
-    DomainRangeSizeMismatchError
+    DomainRangeSizeMismatchError,
+    /// Occurs when a string to be parsed as a permutation contains a token that is not a non-negative integer,
+    /// the token is contained in the error.
+    ParseError(String),
+    /// Occurs when attempting to create an injective map into a set that is smaller than its domain.
+    NoInjectionError
 }
 
 
@@ -355,6 +824,7 @@ pub enum PermutationError {
 #[cfg(test)]
 mod tests {
     use crate::permutation::*;
+    use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
 
     #[test]
     fn permutation_constructor_test() {
@@ -370,7 +840,14 @@ mod tests {
         let image_vec2 = vec![2, 6, 4, 1, 3];
         assert_eq!(
             Permutation::new(image_vec2),
-            Err(PermutationError::NotBijectiveError)
+            Err(PermutationError::ImageOutOfRangeError)
+        );
+
+        println!("Attempt to create a permutation that has 0 as an image.");
+
+        assert_eq!(
+            Permutation::new(vec![0, 1, 2]),
+            Err(PermutationError::ImageOutOfRangeError)
         );
 
         println!("Attempt to create a permutation that is not bijective.");
@@ -460,6 +937,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn large_inverse_test() {
+        println!("Computing the inverse of a S_5000 permutation.");
+
+        // i -> 7i mod 5000 is a bijection on {0, ..., 4999} since 7 and 5000 are coprime
+        let sigma = Permutation::new(
+            (0..5000).map(|i| (7 * i) % 5000 + 1).collect()
+        ).unwrap();
+
+        let sigma_inverse = sigma.inverse();
+
+        println!("Asserting that the inverse composed with the permutation is the identity.");
+
+        assert_eq!(compose(&sigma_inverse, &sigma), identity(5000));
+        assert_eq!(compose(&sigma, &sigma_inverse), identity(5000));
+
+        println!("Asserting that inverting twice yields the original permutation.");
+
+        assert_eq!(sigma_inverse.inverse(), sigma);
+    }
+
     #[test]
     fn transposition_test() {
         println!("Creating transposition in S_5.");
@@ -489,7 +987,7 @@ mod tests {
 
         assert_eq!(
             transposition(5, 2, 426),
-            Err(PermutationError::NotBijectiveError)
+            Err(PermutationError::ImageOutOfRangeError)
         );
     }
 
@@ -522,6 +1020,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compose_padded() {
+        let sigma = Permutation::new(vec![2, 3, 1]).unwrap();
+
+        println!("Composing an S_3 permutation with the S_5 identity.");
+
+        assert_eq!(
+            compose_padded(&sigma, &identity(5).unwrap()),
+            Permutation::new(vec![2, 3, 1, 4, 5]).unwrap()
+        );
+        assert_eq!(
+            compose_padded(&identity(5).unwrap(), &sigma),
+            Permutation::new(vec![2, 3, 1, 4, 5]).unwrap()
+        );
+
+        println!("Composing an S_3 permutation with an S_4 transposition.");
+
+        assert_eq!(
+            compose_padded(&sigma, &transposition(4, 3, 4).unwrap()),
+            Permutation::new(vec![2, 3, 4, 1]).unwrap()
+        );
+
+        println!("Asserting that compose_padded agrees with compose for permutations from the same group.");
+
+        let tau = transposition(3, 1, 2).unwrap();
+
+        assert_eq!(Ok(compose_padded(&sigma, &tau)), compose(&sigma, &tau));
+    }
+
     #[test]
     fn conjugate_test() {
         println!("Conjugating an S_4 transposition with another S_4 permutation.");
@@ -648,4 +1175,378 @@ mod tests {
 
         assert_eq!(sigma.to_string(), "(1 5 4)(2 6)");
     }
+
+    #[test]
+    fn test_to_transpositions() {
+        println!("Decomposing an S_6 permutation into transpositions.");
+
+        let sigma = Permutation::new(
+            vec![5, 6, 3, 1, 4, 2]
+        ).unwrap();
+
+        let transpositions = sigma.to_transpositions();
+
+        // (1 5 4)(2 6)(3) yields 2 + 1 + 0 transpositions
+        assert_eq!(transpositions.len(), 3);
+
+        println!("Asserting that composing the transpositions reproduces the permutation.");
+
+        let mut composition = identity(6).unwrap();
+        for &(i, j) in transpositions.iter() {
+            composition = compose(&composition, &transposition(6, i, j).unwrap()).unwrap();
+        }
+
+        assert_eq!(composition, sigma);
+
+        println!("Asserting that the parity of the number of transpositions matches the sign.");
+
+        assert_eq!(sigma.sign(), if transpositions.len().is_multiple_of(2) { 1 } else { -1 });
+
+        println!("Asserting that the identity decomposes into no transpositions.");
+
+        assert_eq!(identity(5).unwrap().to_transpositions(), vec![]);
+    }
+
+    #[test]
+    fn test_cycles_iterator() {
+        println!("Asserting that collecting the cycle iterator yields the cycle form.");
+
+        let permutations = [
+            transposition(4, 2, 3).unwrap(),
+            Permutation::new(vec![2, 3, 4, 5, 6, 7, 1]).unwrap(),
+            Permutation::new(vec![5, 6, 3, 1, 4, 2]).unwrap(),
+            identity(426).unwrap()
+        ];
+
+        for sigma in permutations.iter() {
+            assert_eq!(sigma.cycles().collect::<Vec<Cycle>>(), sigma.to_cycle_form());
+        }
+
+        println!("Asserting that the iterator can be consumed lazily.");
+
+        let rho = Permutation::new(vec![5, 6, 3, 1, 4, 2]).unwrap();
+        let mut cycles = rho.cycles();
+
+        assert_eq!(cycles.next(), Some(Cycle::new(vec![1, 5, 4], 6).unwrap()));
+        assert_eq!(cycles.next(), Some(Cycle::new(vec![2, 6], 6).unwrap()));
+    }
+
+    #[test]
+    fn test_as_lookup() {
+        let sigma = Permutation::new(vec![3, 5, 1, 2, 4]).unwrap();
+        let table = sigma.as_lookup();
+
+        println!("Asserting that the lookup table reproduces the evaluation of the permutation.");
+        assert_eq!(table.len(), sigma.n());
+        for i in 1..=sigma.n() {
+            assert_eq!(table[i - 1], sigma.eval(i).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_cycle_form_reduced() {
+        println!("Asserting that the reduced cycle form of a transposition in S_4 is a single 2-cycle.");
+        let tau = transposition(4, 1, 3).unwrap();
+        assert_eq!(tau.to_cycle_form_reduced(), vec![Cycle::new(vec![1, 3], 4).unwrap()]);
+
+        println!("Asserting that the reduced cycle form of the identity is empty.");
+        assert_eq!(identity(4).unwrap().to_cycle_form_reduced(), vec![]);
+    }
+
+    #[test]
+    fn test_sign_fast() {
+        // seeded random number generator, so the test is deterministic
+        let mut rng = StdRng::seed_from_u64(426);
+
+        println!("Asserting that sign_fast agrees with sign on random permutations up to n=200.");
+        for n in 1..=200 {
+            let mut images = (1..=n).collect::<Vec<usize>>();
+            images.shuffle(&mut rng);
+
+            let sigma = Permutation::new(images).unwrap();
+            assert_eq!(sigma.sign_fast(), sigma.sign());
+        }
+    }
+
+    #[test]
+    fn test_conjugate_by_rotation() {
+        let tau = transposition(4, 1, 2).unwrap();
+
+        println!("Asserting that rotating (1 2) in S_4 by 1 yields (2 3).");
+        assert_eq!(tau.conjugate_by_rotation(1), transposition(4, 2, 3).unwrap());
+
+        println!("Asserting that rotating by 3 wraps around and yields (4 1).");
+        assert_eq!(tau.conjugate_by_rotation(3), transposition(4, 4, 1).unwrap());
+
+        println!("Asserting that rotating by a multiple of n does not change the permutation.");
+        assert_eq!(tau.conjugate_by_rotation(8), tau);
+    }
+
+    #[test]
+    fn test_is_valid_permutation() {
+        println!("Validating some candidate image vectors.");
+
+        assert!(is_valid_permutation(&[3, 1, 2]));
+        assert!(!is_valid_permutation(&[1, 1, 2]));
+        assert!(!is_valid_permutation(&[2, 3, 4]));
+        assert!(!is_valid_permutation(&[]));
+    }
+
+    #[test]
+    fn test_cycle_statistics() {
+        let sigma = Permutation::new(vec![2, 3, 1, 5, 4, 6]).unwrap();
+
+        println!("Asserting that (1 2 3)(4 5) in S_6 has 3 cycles of lengths 3, 2 and 1.");
+        let (num_cycles, histogram) = sigma.cycle_statistics();
+        assert_eq!(num_cycles, 3);
+        assert_eq!(histogram, HashMap::from([(3, 1), (2, 1), (1, 1)]));
+
+        println!("Asserting that the identity in S_4 has 4 fixed points.");
+        assert_eq!(identity(4).unwrap().cycle_statistics(), (4, HashMap::from([(1, 4)])));
+    }
+
+    #[test]
+    fn test_support() {
+        let sigma = Permutation::new(vec![1, 3, 2, 4]).unwrap();
+
+        println!("Asserting that [1, 3, 2, 4] has support [2, 3] and fixed points [1, 4].");
+        assert_eq!(sigma.support(), vec![2, 3]);
+        assert_eq!(sigma.support_size(), 2);
+        assert_eq!(sigma.fixed_points(), vec![1, 4]);
+
+        println!("Asserting that the identity has empty support.");
+        let id = identity(4).unwrap();
+        assert_eq!(id.support(), Vec::<usize>::new());
+        assert_eq!(id.support_size(), 0);
+    }
+
+    #[test]
+    fn test_commutes() {
+        println!("Asserting that the disjoint transpositions (1 2) and (3 4) commute.");
+        assert_eq!(commutes(&transposition(4, 1, 2).unwrap(), &transposition(4, 3, 4).unwrap()), Ok(true));
+
+        println!("Asserting that the overlapping transpositions (1 2) and (2 3) do not commute.");
+        assert_eq!(commutes(&transposition(4, 1, 2).unwrap(), &transposition(4, 2, 3).unwrap()), Ok(false));
+
+        println!("Asserting that permutations from different symmetric groups are rejected.");
+        assert_eq!(
+            commutes(&transposition(4, 1, 2).unwrap(), &transposition(3, 1, 2).unwrap()), 
+            Err(PermutationError::DomainRangeSizeMismatchError)
+        );
+    }
+
+    #[test]
+    fn test_act_on_pairs() {
+        /*
+        * The pairs of {1, 2, 3} are numbered {1, 2} -> 1, {1, 3} -> 2, {2, 3} -> 3.
+        * The transposition (1 2) fixes {1, 2} and swaps {1, 3} and {2, 3}.
+        */
+        println!("Asserting that (1 2) in S_3 induces (2 3) on the 3 pairs.");
+        let tau = transposition(3, 1, 2).unwrap();
+        assert_eq!(tau.act_on_pairs(), transposition(3, 2, 3).unwrap());
+
+        println!("Asserting that the 3-cycle (1 2 3) induces a 3-cycle on the pairs.");
+        let sigma = Permutation::new(vec![2, 3, 1]).unwrap();
+        // {1, 2} -> {2, 3}, {1, 3} -> {1, 2}, {2, 3} -> {1, 3}
+        assert_eq!(sigma.act_on_pairs(), Permutation::new(vec![3, 1, 2]).unwrap());
+
+        println!("Asserting that the induced permutation in S_4 lives in S_6.");
+        assert_eq!(transposition(4, 1, 4).unwrap().act_on_pairs().n(), 6);
+        assert_eq!(identity(4).unwrap().act_on_pairs(), identity(6).unwrap());
+
+        println!("Asserting that permutations in S_1 and S_2 induce the identity of S_1.");
+        assert_eq!(identity(1).unwrap().act_on_pairs(), identity(1).unwrap());
+        assert_eq!(transposition(2, 1, 2).unwrap().act_on_pairs(), identity(1).unwrap());
+    }
+
+    #[test]
+    fn test_parse_one_line() {
+        let expected = Permutation::new(vec![3, 1, 2]);
+
+        println!("Parsing whitespace- and comma-separated image lists.");
+        assert_eq!(parse_one_line("3 1 2"), expected);
+        assert_eq!(parse_one_line("3,1,2"), expected);
+        assert_eq!(parse_one_line(" 3, 1,\t2\n"), expected);
+
+        println!("Asserting that invalid image lists and tokens are rejected.");
+        assert_eq!(parse_one_line("3 1 1"), Err(PermutationError::NotBijectiveError));
+        assert_eq!(parse_one_line("3 x 2"), Err(PermutationError::ParseError("x".to_string())));
+        assert_eq!(parse_one_line(""), Err(PermutationError::EmptyImageVectorError));
+    }
+
+    #[test]
+    fn test_order_and_pow() {
+        let sigma = Permutation::new(vec![2, 3, 1, 5, 4, 6]).unwrap();
+
+        println!("Asserting that (1 2 3)(4 5) has order 6.");
+        assert_eq!(sigma.order(), 6);
+        assert_eq!(identity(4).unwrap().order(), 1);
+
+        println!("Asserting that orders beyond the i32 range are computed exactly.");
+        // cycles of all prime lengths up to 41, followed by a second 31-cycle
+        let mut images = vec![];
+        let mut start = 1;
+        for length in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 31] {
+            images.extend((start + 1)..(start + length));
+            images.push(start);
+            start += length;
+        }
+        assert_eq!(Permutation::new(images).unwrap().order(), 304250263527210);
+
+        println!("Asserting that powers agree with repeated composition.");
+        let mut power = identity(6).unwrap();
+        for k in 0..8 {
+            assert_eq!(sigma.pow(k), power);
+            power = compose(&sigma, &power).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_cyclic_subgroup() {
+        let sigma = Permutation::new(vec![2, 3, 1, 4]).unwrap();
+        let subgroup = sigma.cyclic_subgroup();
+
+        println!("Asserting that a 3-cycle generates a 3-element subgroup of S_4.");
+        assert_eq!(subgroup.len(), 3);
+        assert_eq!(subgroup[0], identity(4).unwrap());
+        assert_eq!(subgroup[1], sigma);
+        assert_eq!(subgroup[2], Permutation::new(vec![3, 1, 2, 4]).unwrap());
+        assert!(subgroup.iter().all(|tau| tau.n() == 4 && is_valid_permutation(&tau.as_lookup())));
+
+        println!("Asserting that the identity generates the trivial subgroup.");
+        assert_eq!(identity(4).unwrap().cyclic_subgroup(), vec![identity(4).unwrap()]);
+    }
+
+    #[test]
+    fn test_cycles_of_length() {
+        println!("Asserting that S_3 contains exactly the two 3-cycles (1 2 3) and (1 3 2).");
+        let three_cycles = cycles_of_length(3, 3);
+        assert_eq!(three_cycles.len(), 2);
+        assert!(three_cycles.contains(&Permutation::new(vec![2, 3, 1]).unwrap()));
+        assert!(three_cycles.contains(&Permutation::new(vec![3, 1, 2]).unwrap()));
+
+        println!("Asserting that the 2-cycles in S_4 are the 6 transpositions.");
+        let transpositions = cycles_of_length(4, 2);
+        assert_eq!(transpositions.len(), 6);
+        for i in 1..5 {
+            for j in (i+1)..5 {
+                assert!(transpositions.contains(&transposition(4, i, j).unwrap()));
+            }
+        }
+
+        println!("Asserting that the number of 4-cycles in S_6 is 6!/(2! * 4) = 90.");
+        let four_cycles = cycles_of_length(6, 4);
+        assert_eq!(four_cycles.len(), 90);
+        assert!(four_cycles.iter().all(|sigma| sigma.support_size() == 4 && sigma.order() == 4));
+
+        println!("Checking degenerate lengths.");
+        assert_eq!(cycles_of_length(4, 1), vec![identity(4).unwrap()]);
+        assert!(cycles_of_length(4, 0).is_empty());
+        assert!(cycles_of_length(4, 5).is_empty());
+    }
+
+    #[test]
+    fn test_apply_cycle() {
+        println!("Applying the cycle (1 2) to the identity in S_3.");
+        let transposition_12 = identity(3).unwrap().apply_cycle(&Cycle::new(vec![1, 2], 3).unwrap()).unwrap();
+        assert_eq!(transposition_12, Permutation::new(vec![2, 1, 3]).unwrap());
+
+        println!("Asserting that the cycle is applied first: (1 2 3)(1 2) = (1 3).");
+        let sigma = Permutation::new(vec![2, 3, 1]).unwrap();
+        assert_eq!(
+            sigma.apply_cycle(&Cycle::new(vec![1, 2], 3).unwrap()).unwrap(),
+            Permutation::new(vec![3, 2, 1]).unwrap()
+        );
+
+        println!("Building (1 2 3)(4 5) as a product of cycles.");
+        let product = identity(5).unwrap()
+            .apply_cycle(&Cycle::new(vec![1, 2, 3], 5).unwrap()).unwrap()
+            .apply_cycle(&Cycle::new(vec![4, 5], 5).unwrap()).unwrap();
+        assert_eq!(product, Permutation::new(vec![2, 3, 1, 5, 4]).unwrap());
+
+        println!("Asserting that a cycle from a larger symmetric group is rejected.");
+        assert_eq!(
+            identity(3).unwrap().apply_cycle(&Cycle::new(vec![1, 4], 4).unwrap()),
+            Err(PermutationError::DomainRangeSizeMismatchError)
+        );
+    }
+
+    #[test]
+    fn test_generated_subgroup() {
+        println!("Asserting that (1 2) and (1 2 3) generate all 6 elements of S_3.");
+        let generators = vec![transposition(3, 1, 2).unwrap(), Permutation::new(vec![2, 3, 1]).unwrap()];
+        let subgroup = generated_subgroup(&generators).unwrap();
+        assert_eq!(subgroup.len(), 6);
+        assert_eq!(subgroup[0], identity(3).unwrap());
+        for sigma in cycles_of_length(3, 2).iter().chain(cycles_of_length(3, 3).iter()) {
+            assert!(subgroup.contains(sigma));
+        }
+
+        println!("Asserting that (1 2) and (1 2 3 4) generate all 24 elements of S_4.");
+        let generators = vec![transposition(4, 1, 2).unwrap(), Permutation::new(vec![2, 3, 4, 1]).unwrap()];
+        assert_eq!(generated_subgroup(&generators).unwrap().len(), 24);
+
+        println!("Asserting that a single generator yields its cyclic subgroup.");
+        let sigma = Permutation::new(vec![2, 3, 1, 5, 4]).unwrap();
+        assert_eq!(generated_subgroup(&[Permutation::new(vec![2, 3, 1, 5, 4]).unwrap()]).unwrap().len(), sigma.order());
+
+        println!("Checking erroneous generator sets.");
+        assert_eq!(generated_subgroup(&[]), Err(PermutationError::EmptyImageVectorError));
+        assert_eq!(
+            generated_subgroup(&[identity(3).unwrap(), identity(4).unwrap()]),
+            Err(PermutationError::DomainRangeSizeMismatchError)
+        );
+    }
+
+    #[test]
+    fn test_to_canonical_string() {
+        println!("Constructing (1 3 2)(4 5) from its table form and as a product of cycles.");
+        let sigma = Permutation::new(vec![3, 1, 2, 5, 4]).unwrap();
+        let tau = identity(5).unwrap()
+            .apply_cycle(&Cycle::new(vec![5, 4], 5).unwrap()).unwrap()
+            .apply_cycle(&Cycle::new(vec![2, 1, 3], 5).unwrap()).unwrap();
+
+        println!("Asserting that both have the same canonical string.");
+        assert_eq!(sigma.to_canonical_string(), String::from("(1 3 2)(4 5)"));
+        assert_eq!(tau.to_canonical_string(), sigma.to_canonical_string());
+
+        println!("Asserting that different permutations have different canonical strings.");
+        assert!(sigma.inverse().to_canonical_string() != sigma.to_canonical_string());
+
+        println!("Asserting that the identity is represented by the empty cycle.");
+        assert_eq!(identity(426).unwrap().to_canonical_string(), String::from("()"));
+    }
+
+    #[test]
+    fn test_inverse_cycle_form() {
+        println!("Asserting that the inverse of the 3-cycle (1 2 3) has cycle form (1 3 2).");
+        let sigma = Permutation::new(vec![2, 3, 1]).unwrap();
+        assert_eq!(sigma.inverse_cycle_form(), vec![Cycle::new(vec![1, 3, 2], 3).unwrap()]);
+
+        println!("Asserting that the result agrees with the cycle form of the inverse.");
+        let tau = Permutation::new(vec![4, 6, 1, 3, 5, 2, 7]).unwrap();
+        assert_eq!(tau.inverse_cycle_form(), tau.inverse().to_cycle_form());
+        assert_eq!(identity(4).unwrap().inverse_cycle_form(), identity(4).unwrap().to_cycle_form());
+    }
+
+    #[test]
+    fn test_random_injection() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(426);
+
+        println!("Asserting that random injections have distinct images in range.");
+        for (domain, codomain) in [(0, 5), (3, 10), (7, 7), (10, 426)] {
+            let images = random_injection(domain, codomain, &mut rng).unwrap();
+            assert_eq!(images.len(), domain);
+            assert!(images.iter().all(|&x| 1 <= x && x <= codomain));
+            assert_eq!(images.iter().collect::<HashSet<&usize>>().len(), domain);
+        }
+
+        println!("Asserting that equal domain and codomain yield a permutation.");
+        let images = random_injection(426, 426, &mut rng).unwrap();
+        assert!(is_valid_permutation(&images));
+
+        println!("Asserting that a domain larger than the codomain is rejected.");
+        assert_eq!(random_injection(5, 4, &mut rng), Err(PermutationError::NoInjectionError));
+    }
 }
\ No newline at end of file
@@ -3,14 +3,20 @@
 
 
 use std::cmp::max; // maximum function
+use std::ops::{Add, Mul, Neg, Sub}; // for polynomial arithmetic with operators
+use std::fmt; // for displaying polynomials
+use std::str::FromStr; // for parsing polynomials
+use rand::Rng; // for generating random polynomials
 use crate::vec_helper::*; // helper functions for operating on coefficient vectors
+use crate::matrix::{Matrix, MatrixError, add, identity_matrix}; // for evaluating polynomials at matrices
+use crate::number_theory::{euclid, is_prime, mod_inverse, mod_pow, mul_mod, reduce_mod, reduce_mod_wide}; // integer gcds and arithmetic in prime fields
 
 /// Models a polynomial a_0 + a_1 * X + ... + a_n * X^n with either integer coefficients 
 /// or coefficients from a remainder class ring. 
 /// 
 /// Polynomials are stored as coefficient vectors
 /// and the coefficient for the highest-degree monomial is guaranteed to be != 0.
-#[derive(PartialEq, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct IntPoly {
     /// Vector of coefficients of the polynomial,
     /// coeff[i] is the coefficient for X^i.
@@ -31,7 +37,24 @@ impl IntPoly {
     /// i.e. 1 + qX + 3qX^2 would become 1.
     ///
     /// Any coefficients for monomials with higher degrees than the explicitly listed ones are 0. 
-    pub fn new(coeff:&mut Vec<i32>, md: Modulus) -> IntPoly {
+    ///
+    /// The modulus q of a remainder class ring Z/qZ has to be at least 2, 
+    /// otherwise an error is returned.
+    pub fn new(coeff:&mut Vec<i32>, md: Modulus) -> Result<IntPoly, PolynomialError> {
+        if let Modulus::Some(q) = md {
+            if q < 2 {
+                return Err(PolynomialError::InvalidModulusError(q));
+            }
+        }
+
+        Ok(IntPoly::new_unchecked(coeff, md))
+    }
+
+    /*
+    * Constructor without validation of the modulus, used internally 
+    * where the modulus is known to be valid (e.g. since it stems from an existing polynomial).
+    */
+    pub(crate) fn new_unchecked(coeff:&mut Vec<i32>, md: Modulus) -> IntPoly {
         // trim trailing zeros/ multiples of modulus
         remove_trailing_zeros(coeff, md);
         
@@ -45,6 +68,7 @@ impl IntPoly {
     ///
     /// For polynomials over remainder class ring Z/qZ, 
     /// we reduce the coefficient to the standard representative system {0, ..., q-1}.
+    /// Use coefficient_in to reduce to another system of representatives.
     pub fn coefficient(self: &Self, exponent: usize) -> i32 {
         /*
         * Coefficients that are not explicitly listed in the self.coefficients vector are 0.
@@ -63,11 +87,57 @@ impl IntPoly {
         }
         
         match self.modulus {
-            Modulus::Some(q) => self.coefficients[exponent] % q,
+            Modulus::Some(q) => reduce_mod(self.coefficients[exponent], q),
             Modulus::None => self.coefficients[exponent]
         }
     }
     
+    /// Returns the coefficient for the monomial with the passed exponent, 
+    /// reduced to the passed system of representatives for polynomials over remainder class ring Z/qZ.
+    ///
+    /// Coefficients of polynomials over the integers are returned as they are.
+    pub fn coefficient_in(self: &Self, exponent: usize, system: Representatives) -> i32 {
+        let a = if exponent < self.coefficients.len() { self.coefficients[exponent] } else { 0 };
+
+        match self.modulus {
+            Modulus::Some(q) => {
+                let least_non_negative = reduce_mod(a, q);
+
+                match system {
+                    Representatives::LeastNonNegative => least_non_negative,
+                    // residues above q/2 are closer to 0 when q is subtracted
+                    Representatives::Symmetric => if least_non_negative > q / 2 { least_non_negative - q } else { least_non_negative }
+                }
+            },
+            Modulus::None => a
+        }
+    }
+
+    /// Determines whether all stored coefficients of the polynomial are canonical, 
+    /// i.e. from the standard representative system {0, ..., q-1} for polynomials over Z/qZ.
+    ///
+    /// Polynomials over the integers are always reduced.
+    pub fn is_reduced(self: &Self) -> bool {
+        match self.modulus {
+            Modulus::Some(q) => self.coefficients.iter().all(|&a_i| 0 <= a_i && a_i < q),
+            Modulus::None => true
+        }
+    }
+
+    /// Returns an equivalent polynomial whose stored coefficients are all canonical,
+    /// i.e. reduced to the standard representative system {0, ..., q-1} for polynomials over Z/qZ.
+    ///
+    /// Polynomials over the integers are returned unchanged.
+    pub fn reduced(self: &Self) -> IntPoly {
+        match self.modulus {
+            Modulus::Some(q) => IntPoly::new_unchecked(
+                &mut self.coefficients.iter().map(|&a_i| reduce_mod(a_i, q)).collect(),
+                self.modulus
+            ),
+            Modulus::None => self.clone()
+        }
+    }
+
     /// Computes the degree of the passed polynomial.
     /// Exploits the fact that trailing zeros are cut from the polynomial upon instantiation,
     /// i.e. 1 + X + 0X^2 + 4X^3 + 0X^4 becomes 1 + X + 0X^2 + 4X^3.
@@ -83,53 +153,721 @@ impl IntPoly {
         else { (self.coefficients.len() - 1).try_into().unwrap() }
     }
 
+    /// Returns the polynomial over remainder class ring Z/qZ obtained by raising every coefficient 
+    /// to the power e modulo q, where the coefficients are reduced to {0, ..., q-1}.
+    ///
+    /// Over a prime field Z/pZ, this map is the identity for e = p by Fermat's little theorem.
+    /// Only the coefficients up to the degree are mapped, so for e = 0 these all become 1.
+    /// If the polynomial is an integer polynomial without modulus, an error is returned.
+    pub fn map_coefficients_pow(self: &Self, e: u64) -> Result<IntPoly, PolynomialError> {
+        let q = match self.modulus {
+            Modulus::Some(q) => q,
+            Modulus::None => return Err(PolynomialError::NoModulusError)
+        };
+
+        Ok(IntPoly::new_unchecked(
+            &mut self.coefficients.iter().map(|&a_i| mod_pow(a_i, e, q)).collect(),
+            self.modulus
+        ))
+    }
+
+    /// Returns the coefficient of the highest-degree monomial of the polynomial,
+    /// which is 0 for the zero polynomial.
+    pub fn leading_coefficient(self: &Self) -> i32 {
+        if self.coefficients.is_empty() { 0 }
+        else { self.coefficient(self.coefficients.len() - 1) }
+    }
+
+    /// Returns the content of the polynomial,
+    /// i.e. the (non-negative) greatest common divisor of its coefficients.
+    ///
+    /// The content of the zero polynomial is 0.
+    pub fn content(self: &Self) -> i32 {
+        self.coefficients.iter().fold(0, |acc, &a_i| euclid(acc, a_i))
+    }
+
+    /// Returns the primitive part of the polynomial,
+    /// i.e. the polynomial divided by its content, 
+    /// with the sign chosen such that the leading coefficient is positive.
+    ///
+    /// The primitive part of the zero polynomial is the zero polynomial.
+    pub fn primitive_part(self: &Self) -> IntPoly {
+        let content = self.content();
+
+        if content == 0 {
+            return self.clone();
+        }
+
+        let divisor = if self.leading_coefficient() < 0 { -content } else { content };
+
+        IntPoly::new_unchecked(
+            &mut self.coefficients.iter().map(|a_i| a_i / divisor).collect(),
+            self.modulus
+        )
+    }
+
     /// Scales the polynomial with the passed scale factor,
     /// i.e. multiplies all the coefficients with it.
     /// The result is returned as a new IntPoly instance,
     /// the original polynomial is not changed.
     pub fn scale(self: &Self, scale_factor: i32) -> IntPoly {
-        IntPoly::new( // this call removes trailing zeros from the passed vector automatically
+        IntPoly::new_unchecked( // this call removes trailing zeros from the passed vector automatically
             &mut scale_vector(&self.coefficients, scale_factor),
             self.modulus
         )
     }
 
+    /// Scales the polynomial with the passed scale factor in place,
+    /// i.e. multiplies all the coefficients with it.
+    ///
+    /// Behaves like scale, but changes this polynomial instead of allocating a new one.
+    pub fn scale_assign(self: &mut Self, scale_factor: i32) {
+        scale_vector_into(&mut self.coefficients, scale_factor);
+
+        // scaling by 0 or a multiple of the modulus can produce trailing zeros
+        remove_trailing_zeros(&mut self.coefficients, self.modulus);
+    }
+
+    /// Adds the passed polynomial to this one in place.
+    /// Trailing zeros of the sum are cut in the process.
+    ///
+    /// Behaves like add_poly, but changes this polynomial instead of allocating a new one.
+    /// If the moduli of the polynomials do not match, an error is returned 
+    /// and this polynomial remains unchanged.
+    pub fn add_assign(self: &mut Self, other: &IntPoly) -> Result<(), PolynomialError> {
+        // two polynomials with non-matching moduli cannot be added meaningfully
+        if self.modulus != other.modulus {
+            return Err(
+                PolynomialError::ModulusMismatchError(self.modulus, other.modulus)
+            );
+        }
+
+        // grow coefficient vector if the other polynomial has a higher degree
+        if other.coefficients.len() > self.coefficients.len() {
+            self.coefficients.resize(other.coefficients.len(), 0);
+        }
+
+        // computing the coefficients just like add_poly does
+        for i in 0..self.coefficients.len() {
+            self.coefficients[i] = self.coefficient(i) + other.coefficient(i);
+        }
+
+        // the sum can have a lower degree than the summands
+        remove_trailing_zeros(&mut self.coefficients, self.modulus);
+
+        Ok(())
+    }
+
     /// Returns the additive inverse of the passed polynomial.
     pub fn additive_inverse(self: &Self) -> IntPoly {
         self.scale(-1)
     }
 
+    /// Returns the reverse (reciprocal) polynomial X^n * p(1/X) of a polynomial p of degree n,
+    /// i.e. the polynomial with the coefficient vector read backwards.
+    ///
+    /// Note that the reverse has a lower degree than p if p is divisible by X. 
+    /// The reverse of the zero polynomial is the zero polynomial.
+    pub fn reverse(self: &Self) -> IntPoly {
+        IntPoly::new_unchecked(
+            &mut self.coefficients.iter().rev().copied().collect(),
+            self.modulus
+        )
+    }
+
+    /// Determines whether the polynomial is palindromic (self-reciprocal),
+    /// i.e. its coefficients read the same forwards and backwards. 
+    ///
+    /// The zero polynomial is palindromic.
+    pub fn is_palindromic(self: &Self) -> bool {
+        // p is palindromic iff p - reverse(p) is the zero polynomial
+        let mut difference = self.clone();
+        difference.add_assign(&self.reverse().additive_inverse()).unwrap(); // same modulus, cannot fail
+        
+        difference.deg() == -1
+    }
+
+    /// Determines whether the polynomial is anti-palindromic,
+    /// i.e. its coefficients read backwards are the negated coefficients read forwards.
+    ///
+    /// The zero polynomial is anti-palindromic.
+    pub fn is_anti_palindromic(self: &Self) -> bool {
+        // p is anti-palindromic iff p + reverse(p) is the zero polynomial
+        let mut sum = self.clone();
+        sum.add_assign(&self.reverse()).unwrap(); // same modulus, cannot fail
+        
+        sum.deg() == -1
+    }
+
+    /// Evaluates the polynomial at the passed integer x,
+    /// i.e. computes a_0 + a_1 * x + ... + a_n * x^n.
+    ///
+    /// Uses Horner's scheme a_0 + x * (a_1 + x * (a_2 + ... + x * a_n)),
+    /// which needs only n multiplications and avoids computing the powers of x.
+    ///
+    /// For polynomials over remainder class ring Z/qZ, the evaluation is delegated to evaluate_mod,
+    /// i.e. the argument and all intermediate results are reduced to {0, ..., q-1}
+    /// and computed with 64 bits, so no overflows occur for any modulus.
+    /// The zero polynomial evaluates to 0 everywhere.
+    pub fn evaluate(self: &Self, x: i32) -> i32 {
+        if let Modulus::Some(_) = self.modulus {
+            return self.evaluate_mod(x).unwrap(); // modulus exists, cannot fail
+        }
+
+        let mut result = 0;
+
+        for &a_i in self.coefficients.iter().rev() {
+            result = result * x + a_i;
+        }
+
+        result
+    }
+
+    /// Evaluates the polynomial over remainder class ring Z/qZ at the passed x 
+    /// and returns the canonical residue from {0, ..., q-1}.
+    ///
+    /// Uses Horner's scheme like evaluate, but the argument and all intermediate results are reduced
+    /// (and computed with 64 bits), so the result is guaranteed to be reduced and no overflows occur.
+    /// If the polynomial is an integer polynomial without modulus, an error is returned.
+    pub fn evaluate_mod(self: &Self, x: i32) -> Result<i32, PolynomialError> {
+        let q = match self.modulus {
+            Modulus::Some(q) => q as i64,
+            Modulus::None => return Err(PolynomialError::NoModulusError)
+        };
+
+        let x = reduce_mod_wide(x as i64, q);
+        let mut result = 0;
+
+        for &a_i in self.coefficients.iter().rev() {
+            result = reduce_mod_wide(result * x + a_i as i64, q);
+        }
+
+        Ok(result as i32)
+    }
+
+    /// Divides the polynomial by the linear factor (X - root) using synthetic division
+    /// and returns the quotient and the remainder.
+    ///
+    /// Synthetic division is Horner's scheme where the intermediate results are kept:
+    /// they are the coefficients of the quotient, while the final result is the remainder,
+    /// which thus equals the value of the polynomial at root.
+    /// For polynomials over remainder class ring Z/qZ, 
+    /// the root and all intermediate results are reduced to the standard representative system {0, ..., q-1}
+    /// (and computed with 64 bits like in evaluate_mod, so no overflows occur).
+    pub fn synthetic_divide(self: &Self, root: i32) -> (IntPoly, i32) {
+        let mut quotient_coeffs = vec![0; max(self.coefficients.len(), 1) - 1];
+        let mut carry = 0;
+
+        // the coefficient of X^(k-1) in the quotient is a_k + root * (coefficient of X^k in the quotient)
+        for k in (0..self.coefficients.len()).rev() {
+            carry = match self.modulus {
+                Modulus::Some(q) => {
+                    let q = q as i64;
+                    reduce_mod_wide((carry as i64) * reduce_mod_wide(root as i64, q) + self.coefficients[k] as i64, q) as i32
+                },
+                Modulus::None => carry * root + self.coefficients[k]
+            };
+
+            if k > 0 {
+                quotient_coeffs[k - 1] = carry;
+            }
+        }
+
+        (IntPoly::new_unchecked(&mut quotient_coeffs, self.modulus), carry)
+    }
+
+    /// Returns the coefficients of the polynomial re-expanded about X = a,
+    /// i.e. the coefficients c_0, ..., c_n with p(X) = c_0 + c_1 (X - a) + ... + c_n (X - a)^n,
+    /// which are the coefficients of p(X + a).
+    /// Over the integers, c_k equals the k-th derivative at a divided by k!.
+    ///
+    /// The coefficients are computed by repeated synthetic division by (X - a):
+    /// the remainder of each division is the next coefficient, the quotient is divided next.
+    /// For polynomials over remainder class ring Z/qZ, 
+    /// the coefficients are reduced to the standard representative system {0, ..., q-1}.
+    /// The zero polynomial yields an empty vector.
+    pub fn taylor_coefficients(self: &Self, a: i32) -> Vec<i32> {
+        let mut result = Vec::<i32>::new();
+        let mut current = self.clone();
+
+        for _ in 0..self.coefficients.len() {
+            let (quotient, remainder) = current.synthetic_divide(a);
+            result.push(remainder);
+            current = quotient;
+        }
+
+        result
+    }
+
+    /// Returns the distinct integer roots of the polynomial in ascending order.
+    ///
+    /// By the rational root theorem, every integer root of an integer polynomial
+    /// with non-zero constant term divides the constant term.
+    /// So all divisors of the constant term (and their negations) are tested,
+    /// where candidates at which the evaluation overflows are no roots (see evaluate_checked).
+    /// If the constant term is 0, then 0 is a root and the remaining roots are the ones of p / X^k
+    /// for the largest k such that X^k divides p.
+    ///
+    /// The zero polynomial (for which every integer is a root) and non-zero constants yield an empty vector.
+    /// The rational root theorem does not apply to remainder class rings,
+    /// so polynomials over Modulus::Some also yield an empty vector.
+    pub fn integer_roots(self: &Self) -> Vec<i32> {
+        if self.modulus != Modulus::None || self.deg() <= 0 {
+            return vec![];
+        }
+
+        let mut roots = Vec::<i32>::new();
+
+        // divide by the highest power of X dividing the polynomial
+        let k = self.coefficients.iter().position(|&a_i| a_i != 0).unwrap(); // exists since the polynomial is non-zero
+        if k > 0 {
+            roots.push(0);
+        }
+        let reduced = IntPoly::new_unchecked(&mut self.coefficients[k..].to_vec(), Modulus::None);
+
+        /*
+        * Collect all divisors of the (now non-zero) constant term c:
+        * they come in pairs d, c/d with d <= sqrt(c), so only O(sqrt(c)) trial divisions are needed.
+        */
+        let constant_term = (reduced.coefficient(0) as i64).abs();
+        let mut divisors = vec![];
+        let mut d = 1;
+        while d <= constant_term / d {
+            if constant_term % d == 0 {
+                divisors.push(d);
+                if d != constant_term / d {
+                    divisors.push(constant_term / d);
+                }
+            }
+            d += 1;
+        }
+
+        // test the divisors and their negations, candidates outside of the i32 range cannot be returned
+        for &d in divisors.iter() {
+            for candidate in [-d, d] {
+                if let Ok(candidate) = i32::try_from(candidate) {
+                    if reduced.evaluate_checked(candidate) == Some(0) {
+                        roots.push(candidate);
+                    }
+                }
+            }
+        }
+
+        roots.sort();
+        roots
+    }
+
+    /*
+    * Evaluates the integer polynomial at x using Horner's scheme with 64-bit checked arithmetic,
+    * None is returned if some intermediate result overflows.
+    *
+    * When x is a root, the intermediate results are the coefficients of the quotient by (X - x),
+    * whose absolute values are at most the sum of the absolute values of the coefficients.
+    * So they always fit into an i64 and an overflow means that x is not a root.
+    */
+    fn evaluate_checked(self: &Self, x: i32) -> Option<i64> {
+        let mut result: i64 = 0;
+
+        for &a_i in self.coefficients.iter().rev() {
+            result = result.checked_mul(x as i64)?.checked_add(a_i as i64)?;
+        }
+
+        Some(result)
+    }
+
+    /// Returns the number of sign changes in the sequence of non-zero coefficients
+    /// a_0, a_1, ..., a_n of the polynomial, zero coefficients are skipped.
+    ///
+    /// By Descartes' rule of signs, this number is an upper bound 
+    /// for the number of positive real roots (counted with multiplicity) of an integer polynomial,
+    /// and differs from it by an even number.
+    ///
+    /// Only meaningful for integer polynomials:
+    /// for polynomials over a remainder class ring, the coefficients are reduced 
+    /// to the non-negative standard representatives, so the result is always 0.
+    pub fn sign_variations(self: &Self) -> usize {
+        let mut variations = 0;
+        let mut last_sign = 0; // sign of the last non-zero coefficient, 0 if there was none yet
+
+        for i in 0..self.coefficients.len() {
+            let sign = self.coefficient(i).signum();
+
+            if sign != 0 {
+                if last_sign != 0 && sign != last_sign {
+                    variations += 1;
+                }
+                last_sign = sign;
+            }
+        }
+
+        variations
+    }
+
+    /// Computes the formal derivative of the polynomial,
+    /// i.e. maps a_0 + a_1 * X + ... + a_n * X^n to a_1 + 2 * a_2 * X + ... + n * a_n * X^(n-1).
+    ///
+    /// The derivative has the same modulus as the polynomial,
+    /// the derivative of a constant polynomial is the zero polynomial.
+    /// For polynomials over remainder class ring Z/qZ,
+    /// the products i * a_i are computed with 64 bits and reduced to {0, ..., q-1}.
+    pub fn derivative(self: &Self) -> IntPoly {
+        IntPoly::new_unchecked( // this call removes trailing zeros (e.g. multiples of the modulus) automatically
+            &mut (1..self.coefficients.len())
+                .map(|i| match self.modulus {
+                    Modulus::Some(q) => ((i as i64 * self.coefficient(i) as i64) % q as i64) as i32,
+                    Modulus::None => (i as i32) * self.coefficient(i)
+                })
+                .collect(),
+            self.modulus
+        )
+    }
+
+    /// Computes the formal antiderivative of the polynomial with constant term 0,
+    /// i.e. maps a_0 + a_1 * X + ... + a_n * X^n to a_0 * X + a_1/2 * X^2 + ... + a_n/(n+1) * X^(n+1).
+    ///
+    /// Over a prime field Z/pZ, the divisions are multiplications with modular inverses,
+    /// so an error is returned if some non-zero a_i has to be divided by a multiple of p.
+    /// Over the integers, an error is returned if some quotient a_i/(i+1) is not an integer.
+    /// For other moduli, an error is returned as well.
+    ///
+    /// The derivative of the antiderivative is the polynomial itself.
+    pub fn integrate(self: &Self) -> Result<IntPoly, PolynomialError> {
+        let mut result_coeffs = vec![0];
+
+        for i in 0..self.coefficients.len() {
+            let divisor = (i + 1) as i32;
+
+            let a_i = match self.modulus {
+                Modulus::Some(p) if is_prime(p) => {
+                    let a_i = reduce_mod(self.coefficients[i], p);
+
+                    if a_i == 0 { 0 } else {
+                        // divisor is invertible iff it is not a multiple of p
+                        match mod_inverse(divisor, p) {
+                            Some(inverse) => (((a_i as i64) * (inverse as i64)) % (p as i64)) as i32,
+                            None => return Err(PolynomialError::NonExactDivisionError)
+                        }
+                    }
+                },
+                Modulus::Some(_) => return Err(PolynomialError::NotAFieldError(self.modulus)),
+                Modulus::None => {
+                    if self.coefficients[i] % divisor != 0 {
+                        return Err(PolynomialError::NonExactDivisionError);
+                    }
+                    self.coefficients[i] / divisor
+                }
+            };
+
+            result_coeffs.push(a_i);
+        }
+
+        Ok(IntPoly::new_unchecked(&mut result_coeffs, self.modulus))
+    }
+
+    /// Determines whether this polynomial divides the other one,
+    /// i.e. whether the remainder of the division of other by this polynomial is the zero polynomial.
+    ///
+    /// Over the integers, a division that fails because of a non-divisible leading coefficient
+    /// means that the quotient would not have integer coefficients, so the result is false then.
+    /// If the moduli of the polynomials do not match, this polynomial is the zero polynomial
+    /// or its leading coefficient is not invertible in Z/qZ, an error is returned.
+    pub fn divides(self: &Self, other: &IntPoly) -> Result<bool, PolynomialError> {
+        match divide_poly(other, self) {
+            Ok((_, remainder)) => Ok(remainder.deg() < 0),
+            Err(PolynomialError::NonExactDivisionError) if self.modulus == Modulus::None => Ok(false),
+            Err(e) => Err(e)
+        }
+    }
+
+    /// Counts the distinct real roots of the integer polynomial in the interval (a, b]
+    /// using Sturm's theorem.
+    ///
+    /// The Sturm sequence of p is p_0 = p, p_1 = p' and p_(k+1) = -(p_(k-1) mod p_k) until the remainder is 0.
+    /// The number of distinct roots in (a, b] is V(a) - V(b), 
+    /// where V(x) is the number of sign changes in the sequence p_0(x), p_1(x), ... (ignoring zeros).
+    /// To stay within the integers, the remainders are computed as positive multiples 
+    /// of the actual remainders, which does not change the signs.
+    ///
+    /// For a >= b, the interval is empty and 0 is returned.
+    /// If the polynomial is over Z/qZ or the zero polynomial (which has infinitely many roots), an error is returned.
+    pub fn count_real_roots_in(self: &Self, a: f64, b: f64) -> Result<usize, PolynomialError> {
+        if self.modulus != Modulus::None {
+            return Err(PolynomialError::NotAnIntegerPolynomialError(self.modulus));
+        }
+        if self.deg() < 0 {
+            return Err(PolynomialError::ZeroPolynomialError);
+        }
+        if a >= b {
+            return Ok(0);
+        }
+
+        // build the Sturm sequence
+        let mut sequence = vec![self.clone()];
+        let mut next = self.derivative();
+        while next.deg() >= 0 {
+            let remainder = positive_pseudo_remainder(&sequence[sequence.len() - 1], &next).additive_inverse();
+            sequence.push(next);
+            next = remainder;
+        }
+
+        // number of sign changes of the sequence evaluated at x
+        let sign_changes = |x: f64| {
+            let signs = sequence.iter()
+                .map(|p| p.coefficients.iter().rev().fold(0.0, |acc, &a_i| acc * x + (a_i as f64)))
+                .filter(|&value| value != 0.0)
+                .map(|value| value > 0.0)
+                .collect::<Vec<bool>>();
+
+            signs.windows(2).filter(|w| w[0] != w[1]).count()
+        };
+
+        Ok(sign_changes(a) - sign_changes(b))
+    }
+
+    /// Computes the squarefree part (also called radical) of a polynomial over a prime field Z/pZ,
+    /// i.e. the product of its distinct irreducible factors, each with multiplicity 1.
+    ///
+    /// It is computed as f / gcd(f, f'), since every irreducible factor g with multiplicity k in f
+    /// has multiplicity k-1 in the derivative f'.
+    /// Note that over Z/pZ, this only yields the radical 
+    /// if no factor has a multiplicity divisible by p (then g^k has derivative 0).
+    ///
+    /// If the polynomial is not over a prime field, an error is returned.
+    pub fn squarefree_part(self: &Self) -> Result<IntPoly, PolynomialError> {
+        // squarefree part of the zero polynomial is the zero polynomial
+        if self.deg() < 0 {
+            return Ok(self.clone());
+        }
+
+        let gcd = poly_gcd(self, &self.derivative())?;
+        let (quotient, _) = divide_poly(self, &gcd)?; // gcd divides the polynomial, so the remainder is 0
+
+        Ok(quotient)
+    }
+
+    /// Determines whether the polynomial has a repeated root (in some extension of its coefficient ring),
+    /// i.e. whether it is divisible by the square of some non-constant polynomial.
+    ///
+    /// This is the case iff gcd(f, f') is non-constant, 
+    /// where the gcd is computed over the integers or over the prime field Z/pZ respectively.
+    ///
+    /// If the polynomial is the zero polynomial or over a remainder class ring that is not a field, 
+    /// an error is returned.
+    pub fn has_repeated_roots(self: &Self) -> Result<bool, PolynomialError> {
+        if self.deg() < 0 {
+            return Err(PolynomialError::ZeroPolynomialError);
+        }
+
+        let gcd = match self.modulus {
+            Modulus::None => integer_poly_gcd(self, &self.derivative()),
+            Modulus::Some(_) => poly_gcd(self, &self.derivative())?
+        };
+
+        Ok(gcd.deg() >= 1)
+    }
+
+    /// Evaluates the polynomial at the passed square matrix A,
+    /// i.e. computes p(A) = a_0 * I + a_1 * A + ... + a_n * A^n where I is the identity matrix.
+    ///
+    /// For polynomials over a remainder class ring, 
+    /// the standard representatives of the coefficients are used.
+    ///
+    /// If the passed matrix is not square, an error variant is returned.
+    pub fn evaluate_at_matrix(self: &Self, a: &Matrix) -> Result<Matrix, MatrixError> {
+        if !a.is_square() {
+            return Err(MatrixError::NotSquareError);
+        }
+
+        let n = a.num_rows();
+
+        // evaluation of the zero polynomial yields the zero matrix
+        let mut result = identity_matrix(n).scale(0.0);
+
+        for k in 0..self.coefficients.len() {
+            result = add(
+                &result, 
+                &a.pow(k)?.scale(self.coefficient(k) as f32)
+            )?;
+        }
+
+        Ok(result)
+    }
+
     /// Computes a string representation of this polynomial,
     /// looking like "1X^0 + 2X^1 + 1X^2"
     pub fn to_string(self: &Self) -> String {
+        self.join_monomials(|a_i, i| a_i.to_string() + "X^" + &i.to_string())
+    }
+
+    /// Computes a compact string representation of this polynomial
+    /// using the passed variable name instead of X,
+    /// e.g. "1 + 2t + t^2" for the variable name "t".
+    ///
+    /// Unlike to_string, the exponents 0 and 1 as well as coefficients 1 and -1 in front of the variable are omitted.
+    /// As in to_string, monomials with coefficient 0 are skipped, the monomials are separated by " + "
+    /// and the zero polynomial is represented as "0".
+    pub fn to_string_with_var(self: &Self, var: &str) -> String {
+        self.join_monomials(|a_i, i| {
+            let coefficient = match (a_i, i) {
+                (_, 0) => a_i.to_string(),
+                (1, _) => String::new(),
+                (-1, _) => String::from("-"),
+                _ => a_i.to_string()
+            };
+
+            match i {
+                0 => coefficient,
+                1 => coefficient + var,
+                _ => coefficient + var + "^" + &i.to_string()
+            }
+        })
+    }
+
+    /*
+    * Joins the representations of the monomials with non-zero coefficients, computed by the passed function
+    * from the coefficient and the exponent, with " + " in ascending order of the exponents.
+    */
+    fn join_monomials(self: &Self, monomial: impl Fn(i32, usize) -> String) -> String {
         /*
         * Special case: representation of polynomial with empty coefficients vector 
         * is not the empty string "" but "0".
         */
-        if self.coefficients.len() == 0{
+        if self.coefficients.is_empty() {
             return String::from("0");
         }
-        
-        let mut string_representation = String::new();
-        
-        for (i, &a_i) in self.coefficients.iter().enumerate() {
-            // add a proper description of each monomial
-            if a_i != 0 {
-                string_representation.push_str(
-                    &(a_i.to_string() + "X^" + &i.to_string())
-                );
+
+        // skip monomials with coefficient 0 (the leading coefficient is non-zero, so there is at least one monomial)
+        self.coefficients.iter().enumerate()
+            .filter(|&(_, &a_i)| a_i != 0)
+            .map(|(i, &a_i)| monomial(a_i, i))
+            .collect::<Vec<String>>()
+            .join(" + ")
+    }
+}
+
+/// Two polynomials are equal if they have the same modulus 
+/// and all their coefficients agree after reduction (see coefficient),
+/// so e.g. 1 + 6X and 1 + X are equal over Z/5Z.
+/// Polynomials with different moduli are never equal.
+impl PartialEq for IntPoly {
+    fn eq(self: &Self, other: &Self) -> bool {
+        if self.modulus != other.modulus {
+            return false;
+        }
+
+        // coefficients beyond the stored ones are 0, so comparing up to the longer vector suffices
+        (0..max(self.coefficients.len(), other.coefficients.len()))
+            .all(|i| self.coefficient(i) == other.coefficient(i))
+    }
+}
+
+/// Formats the polynomial like to_string, e.g. "1X^0 + 2X^1 + 1X^2".
+impl fmt::Display for IntPoly {
+    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+/*
+* Largest exponent accepted by the FromStr implementation,
+* so that a short string like "1X^4000000000" cannot make the parser allocate gigabytes of coefficients.
+*/
+const MAX_PARSED_EXPONENT: usize = 1_000_000;
+
+/// Parses an integer polynomial (over Modulus::None) from the notation produced by to_string,
+/// i.e. monomials like "-3X^2" separated by "+", or "0" for the zero polynomial.
+///
+/// Whitespace around the monomials is ignored and monomials with the same exponent are added up.
+/// If some monomial is malformed or its exponent is larger than 1000000, a parse error containing it is returned.
+/// If adding up the coefficients of some exponent overflows, an overflow error is returned.
+impl FromStr for IntPoly {
+    type Err = PolynomialError;
+
+    fn from_str(s: &str) -> Result<IntPoly, PolynomialError> {
+        if s.trim() == "0" {
+            return Ok(IntPoly::new_unchecked(&mut vec![], Modulus::None));
+        }
+
+        let mut coeffs = Vec::<i32>::new();
+
+        for monomial in s.split('+').map(|m| m.trim()) {
+            let parse_error = || PolynomialError::ParseError(String::from(monomial));
+
+            // a monomial consists of its coefficient and its exponent, separated by "X^"
+            let (coefficient, exponent) = monomial.split_once("X^").ok_or_else(parse_error)?;
+            let coefficient = coefficient.parse::<i32>().map_err(|_| parse_error())?;
+            let exponent = exponent.parse::<usize>().map_err(|_| parse_error())?;
+            if exponent > MAX_PARSED_EXPONENT {
+                return Err(parse_error());
             }
 
-            // add a "+" between the monomials
-            string_representation.push_str(" + ");
+            if coeffs.len() <= exponent {
+                coeffs.resize(exponent + 1, 0);
+            }
+            coeffs[exponent] = coeffs[exponent].checked_add(coefficient).ok_or(PolynomialError::OverflowError)?;
         }
 
-        // remove final "+"
-        string_representation = string_representation[
-            0..string_representation.len()-3
-        ].to_string();
+        Ok(IntPoly::new_unchecked(&mut coeffs, Modulus::None))
+    }
+}
+
+/// Scaling a polynomial by an integer from the right, i.e. &p * c.
+/// Behaves exactly like scale.
+impl Mul<i32> for &IntPoly {
+    type Output = IntPoly;
+
+    fn mul(self, scale_factor: i32) -> IntPoly {
+        self.scale(scale_factor)
+    }
+}
+
+/// Scaling a polynomial by an integer from the left, i.e. c * &p.
+/// Behaves exactly like scale.
+impl Mul<&IntPoly> for i32 {
+    type Output = IntPoly;
+
+    fn mul(self, poly: &IntPoly) -> IntPoly {
+        poly.scale(self)
+    }
+}
+
+/// Sum of two polynomials, i.e. &p + &q.
+/// Behaves like add_poly, but panics with the error if the moduli do not match.
+impl Add for &IntPoly {
+    type Output = IntPoly;
+
+    fn add(self, other: &IntPoly) -> IntPoly {
+        add_poly(self, other).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+}
+
+/// Difference of two polynomials, i.e. &p - &q.
+/// Behaves like subtract_poly, but panics with the error if the moduli do not match.
+impl Sub for &IntPoly {
+    type Output = IntPoly;
+
+    fn sub(self, other: &IntPoly) -> IntPoly {
+        subtract_poly(self, other).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+}
+
+/// Product of two polynomials, i.e. &p * &q.
+/// Behaves like multiply_poly, but panics with the error if the moduli do not match.
+impl Mul for &IntPoly {
+    type Output = IntPoly;
+
+    fn mul(self, other: &IntPoly) -> IntPoly {
+        multiply_poly(self, other).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+}
+
+/// Additive inverse of a polynomial, i.e. -&p.
+/// Behaves exactly like additive_inverse.
+impl Neg for &IntPoly {
+    type Output = IntPoly;
 
-        return string_representation;
+    fn neg(self) -> IntPoly {
+        self.additive_inverse()
     }
 }
 
@@ -167,7 +905,7 @@ pub fn add_poly(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, PolynomialE
     remove_trailing_zeros(&mut result_coeffs, poly1.modulus); // at this point, the polynomials can be assumed to have the same modulus
 
     // creating result polynomial 
-    let result_poly = IntPoly::new(
+    let result_poly = IntPoly::new_unchecked(
         &mut result_coeffs,
         poly1.modulus 
     );
@@ -184,16 +922,16 @@ pub fn add_poly(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, PolynomialE
 pub fn sum_of_polys(poly_vec: &Vec<IntPoly>) -> Result<IntPoly, PolynomialError> {
     // empty sum of polynomials is integer zero polynomial
     if poly_vec.len() == 0 {
-        return Ok(zero_polynomial(Modulus::None));
+        return Ok(IntPoly::new_unchecked(&mut vec![], Modulus::None));
     }
     
     // from here, we can assume that poly_vec contains at least one polynomial
 
-    let mut result = zero_polynomial(poly_vec[0].modulus);
+    let mut result = IntPoly::new_unchecked(&mut vec![], poly_vec[0].modulus);
 
     for poly in poly_vec.iter() { // elements of iterators are references
         // "?"-operator: error is returned to the caller, for situation-dependent error handling
-        result = add_poly(&result, poly)?; 
+        result.add_assign(poly)?; 
     }
 
     Ok(result)
@@ -245,7 +983,7 @@ pub fn multiply_poly(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, Polyno
     // compute intermediate degree-n polynomials aX^i * p as described above
     for i in 0..poly1.deg()+1 {
         poly_vec.push(
-            IntPoly::new(
+            IntPoly::new_unchecked(
                 // compute aX^i * p by scaling the coefficients of X^i * p with a
                 &mut scale_vector(
                     /*
@@ -268,58 +1006,765 @@ pub fn multiply_poly(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, Polyno
         );
     }
 
+    // if poly1 is the zero polynomial, the empty sum would lose the modulus
+    if poly_vec.is_empty() {
+        return Ok(IntPoly::new_unchecked(&mut vec![], the_modulus));
+    }
+
     sum_of_polys(&poly_vec)
 }
 
-/// Computes the product of n polynomials which are passed as a vector of length n.
+/// Returns the product of the two passed polynomials,
+/// detecting overflows of the i32 coefficients.
 /// Trailing zeros of the product are cut in the process.
 ///
-/// If the moduli of the polynomials do not match, the function returns an error. 
+/// Unlike multiply_poly, which silently produces wrong coefficients on overflow,
+/// this function returns an error as soon as some intermediate product or sum of coefficients
+/// does not fit into an i32.
+/// Over remainder class ring Z/qZ, the product is always well-defined:
+/// there, the products are computed with 64 bits and reduced modulo q, so no error occurs.
 ///
-/// If the passed vector is empty, the one polynomial is returned.
-pub fn product_of_polys(poly_vec: &Vec<IntPoly>) -> Result<IntPoly, PolynomialError> {
-    // empty product of polynomials is the (integer) one polynomial
-    if poly_vec.len() == 0 {
-        return Ok(one_polynomial(Modulus::None));
+/// If the moduli of the polynomials do not match, the function returns an error.
+pub fn multiply_poly_checked(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, PolynomialError> {
+    // two polynomials with non-matching moduli cannot be multiplied meaningfully
+    if poly1.modulus != poly2.modulus {
+        return Err(
+            PolynomialError::ModulusMismatchError(poly1.modulus, poly2.modulus)
+        );
     }
 
-    // from here, we can assume that poly_vec contains at least one polynomial
-
-    let mut result = one_polynomial(poly_vec[0].modulus);
-
-    for poly in poly_vec.iter() { // elements of iterators are references
-        // "?"-operator: error is returned to the caller, for situation-dependent error handling
-        result = multiply_poly(&result, poly)?; 
+    // the product of the zero polynomial with any other polynomial is the zero polynomial
+    if poly1.coefficients.is_empty() || poly2.coefficients.is_empty() {
+        return Ok(IntPoly::new_unchecked(&mut vec![], poly1.modulus));
     }
 
-    Ok(result)
+    let n = poly1.coefficients.len();
+    let m = poly2.coefficients.len();
+
+    /*
+    * The coefficient for X^k in the product is the convolution
+    * a_0 * b_k + a_1 * b_(k-1) + ... + a_k * b_0
+    * where each multiplication and addition is checked for overflow
+    * (or, over Z/qZ, reduced modulo q).
+    */
+    let mut result_coeffs: Vec<i32> = vec![0; n + m - 1];
+    for i in 0..n {
+        for j in 0..m {
+            result_coeffs[i + j] = match poly1.modulus {
+                Modulus::Some(q) => {
+                    let q = q as i64;
+                    let product = (poly1.coefficient(i) as i64) * (poly2.coefficient(j) as i64);
+                    reduce_mod_wide(result_coeffs[i + j] as i64 + product, q) as i32
+                },
+                Modulus::None => {
+                    let product = poly1.coefficient(i).checked_mul(poly2.coefficient(j))
+                        .ok_or(PolynomialError::OverflowError)?;
+                    result_coeffs[i + j].checked_add(product)
+                        .ok_or(PolynomialError::OverflowError)?
+                }
+            };
+        }
+    }
+
+    Ok(IntPoly::new_unchecked(&mut result_coeffs, poly1.modulus))
+}
+
+/// Coefficient vectors shorter than this are multiplied with the schoolbook method 
+/// by multiply_poly_karatsuba instead of being split further.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Computes the product of the two passed polynomials using Karatsuba's algorithm,
+/// which needs O(n^1.585) coefficient multiplications instead of O(n^2) for the schoolbook method of multiply_poly.
+/// Trailing zeros of the product are cut in the process.
+///
+/// Both factors f = f_0 + X^k * f_1 and g = g_0 + X^k * g_1 are split in halves,
+/// then only three products f_0 * g_0, f_1 * g_1 and (f_0 + f_1)(g_0 + g_1) are computed recursively
+/// since the middle part f_0 * g_1 + f_1 * g_0 is the third product minus the other two.
+/// Below KARATSUBA_THRESHOLD coefficients, the schoolbook method is faster and used instead.
+///
+/// The intermediate results are computed with 128 bits, so they cannot overflow for integer polynomials either.
+/// For polynomials over remainder class ring Z/qZ, they are reduced to {0, ..., q-1} along the way.
+/// For integer polynomials, an error is returned if some coefficient of the product does not fit into an i32.
+/// If the moduli of the polynomials do not match, the function returns an error as well.
+pub fn multiply_poly_karatsuba(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, PolynomialError> {
+    // two polynomials with non-matching moduli cannot be multiplied meaningfully
+    if poly1.modulus != poly2.modulus {
+        return Err(
+            PolynomialError::ModulusMismatchError(poly1.modulus, poly2.modulus)
+        );
+    }
+
+    // the product of the zero polynomial with any other polynomial is the zero polynomial
+    if poly1.coefficients.is_empty() || poly2.coefficients.is_empty() {
+        return Ok(IntPoly::new_unchecked(&mut vec![], poly1.modulus));
+    }
+
+    let q = match poly1.modulus {
+        Modulus::Some(q) => Some(q as i128),
+        Modulus::None => None
+    };
+
+    let a = (0..poly1.coefficients.len()).map(|i| poly1.coefficient(i) as i128).collect::<Vec<i128>>();
+    let b = (0..poly2.coefficients.len()).map(|j| poly2.coefficient(j) as i128).collect::<Vec<i128>>();
+
+    let mut result_coeffs = karatsuba(&a, &b, q).iter()
+        .map(|&c| i32::try_from(c).map_err(|_| PolynomialError::OverflowError))
+        .collect::<Result<Vec<i32>, PolynomialError>>()?;
+
+    Ok(IntPoly::new_unchecked(&mut result_coeffs, poly1.modulus))
+}
+
+/*
+ * Recursive part of multiply_poly_karatsuba: 
+ * computes the (untrimmed) coefficient vector of length len(a) + len(b) - 1 of the product
+ * of the non-empty coefficient vectors a and b, reducing modulo q if present.
+ */
+fn karatsuba(a: &[i128], b: &[i128], q: Option<i128>) -> Vec<i128> {
+    let reduce = |x: i128| match q {
+        Some(q) => reduce_mod_wide(x, q),
+        None => x
+    };
+
+    let n = a.len();
+    let m = b.len();
+
+    // schoolbook method for short factors
+    if n < KARATSUBA_THRESHOLD || m < KARATSUBA_THRESHOLD {
+        let mut result = vec![0; n + m - 1];
+        for i in 0..n {
+            for j in 0..m {
+                result[i + j] = reduce(result[i + j] + a[i] * b[j]);
+            }
+        }
+        return result;
+    }
+
+    // pad both factors with zeros to the same length l and split them at k: a = a_0 + X^k * a_1, b = b_0 + X^k * b_1
+    let l = max(n, m);
+    let k = l / 2;
+    let mut a_padded = a.to_vec();
+    a_padded.resize(l, 0);
+    let mut b_padded = b.to_vec();
+    b_padded.resize(l, 0);
+    let (a_0, a_1) = a_padded.split_at(k);
+    let (b_0, b_1) = b_padded.split_at(k);
+
+    let low = karatsuba(a_0, b_0, q);
+    let high = karatsuba(a_1, b_1, q);
+
+    // (a_0 + a_1)(b_0 + b_1), where the upper halves are at least as long as the lower ones
+    let a_sum = (0..a_1.len())
+        .map(|i| reduce(a_0.get(i).unwrap_or(&0) + a_1[i]))
+        .collect::<Vec<i128>>();
+    let b_sum = (0..b_1.len())
+        .map(|i| reduce(b_0.get(i).unwrap_or(&0) + b_1[i]))
+        .collect::<Vec<i128>>();
+    let mixed = karatsuba(&a_sum, &b_sum, q);
+
+    // a * b = low + X^k * (mixed - low - high) + X^(2k) * high
+    let mut result = vec![0; 2*l - 1];
+    for (i, &c) in low.iter().enumerate() {
+        result[i] = reduce(result[i] + c);
+        result[i + k] = reduce(result[i + k] - c);
+    }
+    for (i, &c) in high.iter().enumerate() {
+        result[i + 2*k] = reduce(result[i + 2*k] + c);
+        result[i + k] = reduce(result[i + k] - c);
+    }
+    for (i, &c) in mixed.iter().enumerate() {
+        result[i + k] = reduce(result[i + k] + c);
+    }
+
+    // the coefficients beyond the degree of the product stem from the padding and are 0
+    result.truncate(n + m - 1);
+    result
+}
+
+/// Divides the dividend by the divisor using polynomial long division,
+/// returning the quotient and the remainder as a tuple.
+///
+/// The quotient q and remainder r satisfy dividend = q * divisor + r,
+/// where deg(r) < deg(divisor).
+/// Over the integers, the division only succeeds if the leading coefficient of the divisor
+/// divides the leading coefficients of all intermediate remainders,
+/// otherwise an error is returned.
+/// Over a remainder class ring Z/qZ, the leading coefficient of the divisor is inverted,
+/// which is always possible for prime q. 
+/// Otherwise, an error is returned if it is not invertible.
+///
+/// If the moduli of the polynomials do not match or the divisor is the zero polynomial, 
+/// the function returns an error.
+pub fn divide_poly(dividend: &IntPoly, divisor: &IntPoly) -> Result<(IntPoly, IntPoly), PolynomialError> {
+    // two polynomials with non-matching moduli cannot be divided meaningfully
+    if dividend.modulus != divisor.modulus {
+        return Err(
+            PolynomialError::ModulusMismatchError(dividend.modulus, divisor.modulus)
+        );
+    }
+
+    if divisor.deg() < 0 {
+        return Err(PolynomialError::DivisionByZeroError);
+    }
+
+    let the_modulus = dividend.modulus;
+    let lc = divisor.leading_coefficient();
+
+    // over Z/qZ, dividing by the leading coefficient of the divisor means multiplying with its inverse
+    let lc_inverse = match the_modulus {
+        Modulus::Some(q) => Some(mod_inverse(lc, q).ok_or(PolynomialError::NonExactDivisionError)?),
+        Modulus::None => None
+    };
+
+    let mut quotient_coeffs = vec![0; max(dividend.deg() - divisor.deg() + 1, 0) as usize];
+    // over Z/qZ, the remainder is kept reduced so that no sum or difference of coefficients overflows
+    let mut remainder = dividend.reduced();
+
+    while remainder.deg() >= divisor.deg() {
+        /*
+        * Eliminate the leading monomial of the remainder
+        * by subtracting a suitable multiple factor * X^shift of the divisor.
+        */
+        let shift: usize = (remainder.deg() - divisor.deg()).try_into().unwrap(); // non-negative by the loop condition
+        let lead = remainder.leading_coefficient();
+
+        let factor = match (the_modulus, lc_inverse) {
+            (Modulus::Some(q), Some(inverse)) => mul_mod(lead, inverse, q),
+            _ => {
+                if lead % lc != 0 {
+                    return Err(PolynomialError::NonExactDivisionError);
+                }
+                lead / lc
+            }
+        };
+
+        quotient_coeffs[shift] = factor;
+
+        // over Z/qZ, the products with the factor are computed with 64 bits and reduced
+        let scaled_divisor = match the_modulus {
+            Modulus::Some(q) => divisor.coefficients.iter().map(|&d| mul_mod(d, factor, q)).collect(),
+            Modulus::None => scale_vector(&divisor.coefficients, factor)
+        };
+        let subtrahend = IntPoly::new_unchecked(
+            &mut shift_vector(&scaled_divisor, shift),
+            the_modulus
+        );
+        remainder = subtract_poly(&remainder, &subtrahend)?.reduced();
+    }
+
+    Ok((IntPoly::new_unchecked(&mut quotient_coeffs, the_modulus), remainder))
+}
+
+/// Computes the greatest common divisor of two polynomials over a prime field Z/pZ
+/// using the Euclidean algorithm, normalized to be monic (i.e. to have leading coefficient 1).
+///
+/// The gcd of two zero polynomials is the zero polynomial.
+///
+/// If the moduli of the polynomials do not match 
+/// or the polynomials are not over a prime field, an error is returned.
+pub fn poly_gcd(a: &IntPoly, b: &IntPoly) -> Result<IntPoly, PolynomialError> {
+    if a.modulus != b.modulus {
+        return Err(
+            PolynomialError::ModulusMismatchError(a.modulus, b.modulus)
+        );
+    }
+
+    // the Euclidean algorithm (and normalization) requires coefficients from a field
+    let p = match a.modulus {
+        Modulus::Some(p) if is_prime(p) => p,
+        _ => return Err(PolynomialError::NotAFieldError(a.modulus))
+    };
+
+    let mut a = a.clone();
+    let mut b = b.clone();
+
+    // invariant: gcd(a, b) stays the same since every common divisor of a and b divides a mod b
+    while b.deg() >= 0 {
+        let (_, r) = divide_poly(&a, &b)?;
+        a = b;
+        b = r;
+    }
+
+    if a.deg() < 0 {
+        return Ok(a);
+    }
+
+    // leading coefficient is non-zero in Z/pZ, thus invertible
+    let lc_inverse = mod_inverse(a.leading_coefficient(), p).unwrap();
+
+    Ok(IntPoly::new_unchecked(
+        &mut a.coefficients.iter().map(|&a_i| mul_mod(a_i, lc_inverse, p)).collect(),
+        a.modulus
+    ))
+}
+
+/// Computes the interpolation polynomial of the passed sample points (x_i, y_i) over a prime field Z/pZ,
+/// i.e. the unique polynomial f of degree less than the number of points with f(x_i) = y_i for all i.
+///
+/// Uses the Lagrange form f = sum of y_i * L_i where L_i is the product of (X - x_j)/(x_i - x_j) for all j != i.
+/// The numerators of the L_i are obtained by dividing the product of all (X - x_j) by (X - x_i),
+/// so the interpolation needs O(n^2) operations for n points.
+///
+/// If the modulus is not a prime or two sample points have the same x value (modulo p), an error is returned.
+pub fn interpolate(points: &[(i32, i32)], md: Modulus) -> Result<IntPoly, PolynomialError> {
+    let p = match md {
+        Modulus::Some(p) if is_prime(p) => p as i64,
+        _ => return Err(PolynomialError::NotAFieldError(md))
+    };
+
+    // intermediate results are computed with 64 bits to prevent overflows of products
+    let reduce = |a: i64| reduce_mod_wide(a, p);
+    let xs = points.iter().map(|&(x, _)| reduce(x as i64)).collect::<Vec<i64>>();
+    let n = xs.len();
+
+    for i in 0..n {
+        if xs[(i+1)..].contains(&xs[i]) {
+            return Err(PolynomialError::DuplicateSamplePointError);
+        }
+    }
+
+    // coefficients of the product of all (X - x_j), starting with the one polynomial
+    let mut master = vec![1];
+    for &x_j in xs.iter() {
+        let mut next = vec![0; master.len() + 1];
+        for (k, &m_k) in master.iter().enumerate() {
+            next[k + 1] = reduce(next[k + 1] + m_k);
+            next[k] = reduce(next[k] - x_j * m_k);
+        }
+        master = next;
+    }
+
+    let mut result = vec![0; n];
+    for i in 0..n {
+        // divide the master polynomial by (X - x_i) using synthetic division (the remainder is 0)
+        let mut numerator = vec![0; n];
+        let mut carry = 0;
+        for k in (1..(n+1)).rev() {
+            carry = reduce(master[k] + carry * xs[i]);
+            numerator[k - 1] = carry;
+        }
+
+        // the denominator of L_i is the numerator evaluated at x_i, which is non-zero since the x_j are distinct
+        let denominator = numerator.iter().rev().fold(0, |acc, &a_k| reduce(acc * xs[i] + a_k));
+        let factor = reduce(points[i].1 as i64 * mod_inverse(denominator as i32, p as i32).unwrap() as i64);
+
+        for k in 0..n {
+            result[k] = reduce(result[k] + factor * numerator[k]);
+        }
+    }
+
+    Ok(IntPoly::new_unchecked(&mut result.iter().map(|&a_k| a_k as i32).collect(), md))
+}
+
+/// Computes the coefficients of the real interpolation polynomial of the passed sample points (x_i, y_i) in Newton form,
+/// i.e. the divided differences c_k = f[x_0, ..., x_k] with
+/// f = c_0 + c_1 (X - x_0) + c_2 (X - x_0)(X - x_1) + ... + c_(n-1) (X - x_0)...(X - x_(n-2)).
+///
+/// Unlike the Lagrange form, adding a sample point only adds a term, the previous coefficients stay the same.
+/// The divided differences are computed in place in O(n^2) operations using the recursion
+/// f[x_i, ..., x_(i+k)] = (f[x_(i+1), ..., x_(i+k)] - f[x_i, ..., x_(i+k-1)]) / (x_(i+k) - x_i).
+/// Two sample points with the same x value lead to a division by zero, so the result contains infinite or NaN values.
+pub fn divided_differences(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let mut coeffs = points.iter().map(|&(_, y)| y).collect::<Vec<f64>>();
+
+    /*
+    * After step k, coeffs[i] holds f[x_(i-k), ..., x_i] for i >= k, 
+    * so the entries below k are final.
+    */
+    for k in 1..n {
+        for i in (k..n).rev() {
+            coeffs[i] = (coeffs[i] - coeffs[i-1]) / (points[i].0 - points[i-k].0);
+        }
+    }
+
+    coeffs
+}
+
+/// Evaluates the polynomial in Newton form with the passed coefficients c_k (e.g. computed by divided_differences)
+/// with respect to the passed nodes x_k at x, 
+/// i.e. c_0 + c_1 (x - x_0) + ... + c_(n-1) (x - x_0)...(x - x_(n-2)).
+///
+/// Uses a Horner-like scheme c_0 + (x - x_0)(c_1 + (x - x_1)(c_2 + ...)).
+/// The last node is not used, so the nodes are usually the x values of the sample points.
+/// Empty coefficients represent the zero polynomial.
+pub fn eval_newton(coeffs: &[f64], nodes: &[f64], x: f64) -> f64 {
+    let mut result = 0.0;
+
+    for k in (0..coeffs.len()).rev() {
+        result = if k + 1 < coeffs.len() { result * (x - nodes[k]) + coeffs[k] } else { coeffs[k] };
+    }
+
+    result
+}
+
+/// Multiplies two polynomials over a prime field Z/pZ by evaluation and interpolation:
+/// both polynomials are evaluated at deg(poly1) + deg(poly2) + 1 distinct sample points, 
+/// the values are multiplied pointwise 
+/// and the product is the interpolation polynomial of the resulting points.
+///
+/// This works since the product is uniquely determined by its values at more points than its degree.
+/// The sample points are 0, 1, 2, ..., so the prime p has to be greater than deg(poly1) + deg(poly2),
+/// otherwise an error is returned. 
+/// An error is also returned if the moduli do not match or are not a prime.
+pub fn multiply_poly_evaluation(poly1: &IntPoly, poly2: &IntPoly) -> Result<IntPoly, PolynomialError> {
+    if poly1.modulus != poly2.modulus {
+        return Err(
+            PolynomialError::ModulusMismatchError(poly1.modulus, poly2.modulus)
+        );
+    }
+
+    let p = match poly1.modulus {
+        Modulus::Some(p) if is_prime(p) => p,
+        _ => return Err(PolynomialError::NotAFieldError(poly1.modulus))
+    };
+
+    // the product of a zero polynomial with anything is the zero polynomial
+    if poly1.deg() < 0 || poly2.deg() < 0 {
+        return Ok(IntPoly::new_unchecked(&mut vec![], poly1.modulus));
+    }
+
+    // the product has degree deg(poly1) + deg(poly2), so one more point is needed
+    let num_points = poly1.deg() + poly2.deg() + 1;
+    if num_points > p {
+        return Err(PolynomialError::InsufficientSamplePointsError);
+    }
+
+    // the product of the canonical residues is computed with 64 bits, so any prime fitting into an i32 works
+    let points = (0..num_points)
+        .map(|x| {
+            let product = (poly1.evaluate_mod(x).unwrap() as i64) * (poly2.evaluate_mod(x).unwrap() as i64); // modulus exists
+            (x, (product % p as i64) as i32)
+        })
+        .collect::<Vec<(i32, i32)>>();
+
+    interpolate(&points, poly1.modulus)
+}
+
+/// Computes the pseudo-remainder of the division of poly1 by the non-zero polynomial poly2
+/// over the integers.
+///
+/// Since the leading coefficient of poly2 need not divide the one of poly1,
+/// the usual remainder is not always defined over the integers.
+/// Instead, poly1 is multiplied with the leading coefficient of poly2 
+/// before every elimination step of the long division,
+/// and the result has a lower degree than poly2.
+fn pseudo_remainder(poly1: &IntPoly, poly2: &IntPoly) -> IntPoly {
+    let lc = poly2.leading_coefficient();
+    let mut remainder = poly1.clone();
+
+    while remainder.deg() >= poly2.deg() {
+        /*
+        * Eliminate the leading monomial of the remainder by replacing it with
+        * lc * remainder - lead(remainder) * X^(deg(remainder) - deg(poly2)) * poly2.
+        */
+        let shift = (remainder.deg() - poly2.deg()).try_into().unwrap(); // non-negative by the loop condition
+        let subtrahend = IntPoly::new_unchecked(
+            &mut scale_vector(
+                &shift_vector(&poly2.coefficients, shift),
+                remainder.leading_coefficient()
+            ),
+            Modulus::None
+        );
+
+        remainder = subtract_poly(&remainder.scale(lc), &subtrahend).unwrap(); // both are integer polynomials
+    }
+
+    remainder
+}
+
+/// Computes a positive multiple of the remainder of the division of poly1 by the non-zero polynomial poly2
+/// over the rationals, working over the integers only.
+///
+/// Works like pseudo_remainder, but poly1 is multiplied with the absolute value of the leading coefficient of poly2,
+/// so the signs of the result are the ones of the actual remainder.
+/// Afterwards, the result is divided by its (positive) content to keep the coefficients small.
+fn positive_pseudo_remainder(poly1: &IntPoly, poly2: &IntPoly) -> IntPoly {
+    let lc = poly2.leading_coefficient();
+    let mut remainder = poly1.clone();
+
+    while remainder.deg() >= poly2.deg() {
+        /*
+        * Eliminate the leading monomial of the remainder by replacing it with
+        * |lc| * remainder - sign(lc) * lead(remainder) * X^(deg(remainder) - deg(poly2)) * poly2.
+        */
+        let shift = (remainder.deg() - poly2.deg()).try_into().unwrap(); // non-negative by the loop condition
+        let subtrahend = IntPoly::new_unchecked(
+            &mut scale_vector(
+                &shift_vector(&poly2.coefficients, shift),
+                lc.signum() * remainder.leading_coefficient()
+            ),
+            Modulus::None
+        );
+
+        remainder = subtract_poly(&remainder.scale(lc.abs()), &subtrahend).unwrap(); // both are integer polynomials
+    }
+
+    let content = remainder.content();
+    if content > 1 {
+        remainder = IntPoly::new_unchecked(&mut remainder.coefficients.iter().map(|a_i| a_i / content).collect(), Modulus::None);
+    }
+
+    remainder
+}
+
+/// Computes a greatest common divisor of the two passed integer polynomials
+/// using the primitive pseudo-remainder sequence.
+///
+/// In every step of the Euclidean algorithm, the remainder is replaced by 
+/// the primitive part of the pseudo-remainder, 
+/// which keeps all computations within the integers and the coefficients small.
+///
+/// The result is primitive (i.e. the gcd of the contents of the polynomials is not included)
+/// and has a positive leading coefficient, 
+/// so coprime polynomials have the one polynomial as their gcd.
+/// The gcd of two zero polynomials is the zero polynomial.
+///
+/// The coefficients of the passed polynomials are treated as integers,
+/// the result is a polynomial over Modulus::None.
+pub fn integer_poly_gcd(a: &IntPoly, b: &IntPoly) -> IntPoly {
+    let mut a = IntPoly::new_unchecked(&mut a.coefficients.clone(), Modulus::None).primitive_part();
+    let mut b = IntPoly::new_unchecked(&mut b.coefficients.clone(), Modulus::None).primitive_part();
+
+    while b.deg() >= 0 {
+        let r = pseudo_remainder(&a, &b).primitive_part();
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Computes the product of n polynomials which are passed as a vector of length n.
+/// Trailing zeros of the product are cut in the process.
+///
+/// If the moduli of the polynomials do not match, the function returns an error. 
+///
+/// If the passed vector is empty, the one polynomial is returned.
+pub fn product_of_polys(poly_vec: &Vec<IntPoly>) -> Result<IntPoly, PolynomialError> {
+    // empty product of polynomials is the (integer) one polynomial
+    if poly_vec.len() == 0 {
+        return Ok(IntPoly::new_unchecked(&mut vec![1], Modulus::None));
+    }
+
+    // from here, we can assume that poly_vec contains at least one polynomial
+
+    let mut result = IntPoly::new_unchecked(&mut vec![1], poly_vec[0].modulus);
+
+    for poly in poly_vec.iter() { // elements of iterators are references
+        // "?"-operator: error is returned to the caller, for situation-dependent error handling
+        result = multiply_poly(&result, poly)?; 
+    }
+
+    Ok(result)
 }
 
 /// Takes the passed polynomial to the power 
-/// determined by the passed positive exponent.
+/// determined by the passed non-negative exponent, where p^0 is the one polynomial.
+///
+/// Uses binary exponentiation (square-and-multiply), 
+/// so only O(log exponent) polynomial multiplications are performed.
+/// For polynomials over remainder class ring Z/qZ, 
+/// the coefficients are reduced after every multiplication to keep them small.
 pub fn poly_power(poly: &IntPoly, exponent: usize) -> Result<IntPoly, PolynomialError> {
     /*
-    * Need to catch this special case here since
-    * product_of_polys always returns the integer zero polynomial
-    * when called with an empty polynomial vector.
-    * So p^0 for a remainder class ring polynomial would yield a wrong result 
-    * without the following if-clause. 
+    * The result starts as the one polynomial with the modulus of the passed polynomial,
+    * so p^0 is correct for remainder class ring polynomials as well.
+    * Invariant: result * base^e stays p^exponent.
     */
-    if exponent == 0 {
-        return Ok(one_polynomial(poly.modulus));
-    }
-    
-    // create vector of exponent copies of the passed polynomial
-    let mut poly_vec: Vec<IntPoly> = vec![];
-    for _ in 0..exponent {
-        poly_vec.push(poly.clone());
+    let mut result = IntPoly::new_unchecked(&mut vec![1], poly.modulus);
+    let mut base = poly.reduced();
+    let mut e = exponent;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = multiply_poly(&result, &base)?.reduced();
+        }
+
+        e /= 2;
+
+        // the last square would not be used anymore
+        if e > 0 {
+            base = multiply_poly(&base, &base)?.reduced();
+        }
     }
 
-    product_of_polys(&poly_vec) // product_of_polys might throw a PolynomialError
+    Ok(result)
 }  
 
+/// Multiplies the polynomial given by the passed coefficient vector by the linear factor (X - root) in place,
+/// so a product of linear factors can be built in O(n) operations per factor 
+/// without constructing intermediate IntPoly objects.
+///
+/// The coefficient of X^k in the product is a_(k-1) - root * a_k.
+/// Over remainder class ring Z/qZ, the coefficients are reduced to the standard representative system {0, ..., q-1}
+/// (where the products with the root are computed with 64 bits, so no overflows occur)
+/// and trailing zeros are removed, so the vector can be passed to IntPoly::new directly.
+/// The empty vector (zero polynomial) stays empty.
+pub fn multiply_by_linear(coeffs: &mut Vec<i32>, root: i32, md: Modulus) {
+    if coeffs.is_empty() {
+        return;
+    }
+
+    coeffs.push(0);
+
+    // descending order, so a_(k-1) has not been overwritten yet when computing the k-th coefficient
+    for k in (0..coeffs.len()).rev() {
+        let shifted = if k > 0 { coeffs[k-1] } else { 0 };
+
+        // the shifted coefficient and the product are residues, so their difference does not overflow
+        coeffs[k] = match md {
+            Modulus::Some(q) => reduce_mod(reduce_mod(shifted, q) - mul_mod(root, coeffs[k], q), q),
+            Modulus::None => shifted - root * coeffs[k]
+        };
+    }
+
+    remove_trailing_zeros(coeffs, md);
+}
+
+/// Converts the power sums p_1, ..., p_k of some numbers x_1, ..., x_m 
+/// (where p_i = x_1^i + ... + x_m^i)
+/// into the elementary symmetric polynomials e_1, ..., e_k of these numbers
+/// using the Newton identities
+/// k * e_k = e_(k-1) * p_1 - e_(k-2) * p_2 + ... + (-1)^(k-1) * e_0 * p_k with e_0 = 1.
+///
+/// If x_1, ..., x_m are the roots of a monic polynomial, 
+/// then (-1)^i * e_i is its coefficient for X^(m-i).
+///
+/// The divisions by k are exact if the power sums stem from integers.
+/// For arbitrary input sequences, the results of the divisions are truncated.
+pub fn power_sums_to_elementary(power_sums: &[i32]) -> Vec<i32> {
+    // e[i] stores e_i, starting with e_0 = 1
+    let mut e = vec![1];
+
+    for k in 1..(power_sums.len()+1) {
+        let mut sum = 0;
+        for i in 1..(k+1) {
+            let sign = if i % 2 == 1 { 1 } else { -1 };
+            sum += sign * e[k-i] * power_sums[i-1];
+        }
+        e.push(sum / (k as i32));
+    }
+
+    e[1..].to_vec()
+}
+
+/// Converts the elementary symmetric polynomials e_1, ..., e_k of some numbers x_1, ..., x_m
+/// into their power sums p_1, ..., p_k (where p_i = x_1^i + ... + x_m^i)
+/// using the Newton identities
+/// p_k = e_1 * p_(k-1) - e_2 * p_(k-2) + ... + (-1)^(k-2) * e_(k-1) * p_1 + (-1)^(k-1) * k * e_k.
+///
+/// This is the inverse of power_sums_to_elementary.
+pub fn elementary_to_power_sums(elementary: &[i32]) -> Vec<i32> {
+    let mut p = Vec::<i32>::new();
+
+    for k in 1..(elementary.len()+1) {
+        let sign_k = if k % 2 == 1 { 1 } else { -1 };
+        let mut sum = sign_k * (k as i32) * elementary[k-1];
+        for i in 1..k {
+            let sign = if i % 2 == 1 { 1 } else { -1 };
+            sum += sign * elementary[i-1] * p[k-i-1];
+        }
+        p.push(sum);
+    }
+
+    p
+}
+
+/// Returns the n-th Chebyshev polynomial of the first kind T_n over the integers,
+/// i.e. the polynomial with T_n(cos(x)) = cos(n * x).
+///
+/// It is computed using the recurrence T_0 = 1, T_1 = X and T_(k+1) = 2X * T_k - T_(k-1).
+/// Note that the coefficients grow exponentially in n, so they overflow i32 for large n.
+pub fn chebyshev_first_kind(n: usize) -> IntPoly {
+    let x = IntPoly::new_unchecked(&mut vec![0, 1], Modulus::None);
+
+    // T_(k-1) and T_k, starting with k = 0 (T_(-1) only serves as a placeholder)
+    let mut previous = IntPoly::new_unchecked(&mut vec![], Modulus::None);
+    let mut current = IntPoly::new_unchecked(&mut vec![1], Modulus::None);
+
+    for k in 0..n {
+        // T_1 = X does not follow the recurrence
+        let next = if k == 0 { x.clone() } else {
+            subtract_poly(&multiply_poly(&x, &current).unwrap().scale(2), &previous).unwrap()
+        };
+
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+/// Returns the n-th Legendre polynomial P_n, scaled by 2^n to have integer coefficients.
+///
+/// The Legendre polynomials themselves have rational coefficients,
+/// e.g. P_2 = (3X^2 - 1)/2, while 2^n * P_n always has integer coefficients,
+/// e.g. 2^2 * P_2 = 6X^2 - 2. 
+/// The actual Legendre polynomial thus is the returned polynomial divided by 2^n.
+///
+/// The scaled polynomials Q_n = 2^n * P_n are computed from Q_0 = 1 and Q_1 = 2X
+/// using the recurrence (k+1) * Q_(k+1) = 2(2k+1)X * Q_k - 4k * Q_(k-1),
+/// which follows from the recurrence (k+1) * P_(k+1) = (2k+1)X * P_k - k * P_(k-1) of the Legendre polynomials.
+/// Note that the coefficients grow exponentially in n, so they overflow i32 for large n.
+pub fn legendre_polynomial(n: usize) -> IntPoly {
+    let x = IntPoly::new_unchecked(&mut vec![0, 1], Modulus::None);
+
+    // Q_(k-1) and Q_k, starting with k = 0 (Q_(-1) only serves as a placeholder)
+    let mut previous = IntPoly::new_unchecked(&mut vec![], Modulus::None);
+    let mut current = IntPoly::new_unchecked(&mut vec![1], Modulus::None);
+
+    for k in 0..n {
+        let k_i32 = k as i32;
+
+        let numerator = subtract_poly(
+            &multiply_poly(&x, &current).unwrap().scale(2 * (2 * k_i32 + 1)),
+            &previous.scale(4 * k_i32)
+        ).unwrap();
+
+        // the division by k+1 is exact since Q_(k+1) has integer coefficients
+        let (next, _) = divide_poly(&numerator, &IntPoly::new_unchecked(&mut vec![k_i32 + 1], Modulus::None)).unwrap();
+
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+/// Coefficients of random integer polynomials generated by random_poly
+/// are taken from {-RANDOM_COEFFICIENT_BOUND, ..., RANDOM_COEFFICIENT_BOUND}.
+pub const RANDOM_COEFFICIENT_BOUND: i32 = 1000;
+
+/// Generates a random polynomial of exactly the passed degree with the passed modulus.
+///
+/// For polynomials over Z/qZ, the coefficients are taken uniformly from {0, ..., q-1},
+/// for integer polynomials from {-RANDOM_COEFFICIENT_BOUND, ..., RANDOM_COEFFICIENT_BOUND}.
+/// The leading coefficient is drawn from the non-zero values only, so the degree is exact.
+///
+/// If the modulus is invalid (see IntPoly::new), an error is returned.
+pub fn random_poly(degree: usize, md: Modulus, rng: &mut impl Rng) -> Result<IntPoly, PolynomialError> {
+    let (low, high) = match md {
+        Modulus::Some(q) if q >= 2 => (0, q - 1),
+        Modulus::Some(q) => return Err(PolynomialError::InvalidModulusError(q)),
+        Modulus::None => (-RANDOM_COEFFICIENT_BOUND, RANDOM_COEFFICIENT_BOUND)
+    };
+
+    let mut coeffs = (0..degree).map(|_| rng.gen_range(low..=high)).collect::<Vec<i32>>();
+
+    // draw the leading coefficient until it is non-zero
+    let mut leading_coefficient = 0;
+    while leading_coefficient == 0 {
+        leading_coefficient = rng.gen_range(low..=high);
+    }
+    coeffs.push(leading_coefficient);
+
+    Ok(IntPoly::new_unchecked(&mut coeffs, md))
+}
+
 /// Returns the zero polynomial with the passed Modulus.
-pub fn zero_polynomial(md: Modulus) -> IntPoly {
+///
+/// If the modulus is invalid (see IntPoly::new), an error is returned.
+pub fn zero_polynomial(md: Modulus) -> Result<IntPoly, PolynomialError> {
     IntPoly::new(
         &mut vec![],
         md
@@ -327,7 +1772,9 @@ pub fn zero_polynomial(md: Modulus) -> IntPoly {
 }
 
 /// Returns the one polynomial with the passed Modulus.
-pub fn one_polynomial(md: Modulus) -> IntPoly {
+///
+/// If the modulus is invalid (see IntPoly::new), an error is returned.
+pub fn one_polynomial(md: Modulus) -> Result<IntPoly, PolynomialError> {
     IntPoly::new(
         &mut vec![1],
         md
@@ -344,13 +1791,76 @@ pub enum Modulus {
     None
 }
 
+/// A system of representatives for the remainder classes of a remainder class ring Z/qZ.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Representatives {
+    /// The least non-negative residues {0, ..., q-1}.
+    LeastNonNegative,
+    /// The residues of smallest absolute value, 
+    /// i.e. {-(q-1)/2, ..., (q-1)/2} for odd q and {-q/2 + 1, ..., q/2} for even q.
+    Symmetric
+}
+
 /// Models the different error types that can occur when working with polynomials.
 #[derive(Debug, PartialEq)]
 pub enum PolynomialError {
     /*
     * Returned when trying to do some binary operation for polynomials with different moduli.
     */
-    ModulusMismatchError(Modulus, Modulus)
+    ModulusMismatchError(Modulus, Modulus),
+    /*
+    * Returned when a coefficient of the result of some operation does not fit into an i32.
+    */
+    OverflowError,
+    /*
+    * Returned when attempting to divide by the zero polynomial.
+    */
+    DivisionByZeroError,
+    /*
+    * Returned when a polynomial division requires dividing by a coefficient
+    * that is not invertible, e.g. by 2 over the integers.
+    */
+    NonExactDivisionError,
+    /*
+    * Returned when an operation that requires coefficients from a field
+    * is attempted for polynomials over the integers or over Z/qZ for non-prime q.
+    */
+    NotAFieldError(Modulus),
+    /*
+    * Returned when the sample points passed to an interpolation 
+    * do not have pairwise distinct x values.
+    */
+    DuplicateSamplePointError,
+    /*
+    * Returned when the coefficient field does not contain enough distinct sample points
+    * for evaluating and interpolating a polynomial of the required degree.
+    */
+    InsufficientSamplePointsError,
+    /*
+    * Returned when an operation that is only defined for polynomials over Z/qZ
+    * is attempted for an integer polynomial.
+    */
+    NoModulusError,
+    /*
+    * Returned when an operation that is only defined for integer polynomials
+    * is attempted for a polynomial over Z/qZ.
+    */
+    NotAnIntegerPolynomialError(Modulus),
+    /*
+    * Returned when an operation is not defined for the zero polynomial,
+    * e.g. counting its (infinitely many) roots.
+    */
+    ZeroPolynomialError,
+    /*
+    * Returned when a string to be parsed as a polynomial contains a malformed monomial,
+    * the monomial is contained in the error.
+    */
+    ParseError(String),
+    /*
+    * Returned when attempting to create a polynomial over Z/qZ with a modulus q < 2,
+    * the invalid modulus is contained in the error.
+    */
+    InvalidModulusError(i32)
 }
 
 
@@ -364,6 +1874,8 @@ pub enum PolynomialError {
 #[cfg(test)]
 mod tests {
     use crate::poly::*;
+    use crate::approx::ApproxEq;
+    use rand::{Rng, SeedableRng, rngs::StdRng};
 
     #[test]
     fn get_coefficient_test() {
@@ -372,7 +1884,7 @@ mod tests {
         let integer_poly = IntPoly::new(
             &mut vec![2, 3, 2, 1],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(integer_poly.coefficient(0), 2);
         assert_eq!(integer_poly.coefficient(1), 3);
         assert_eq!(integer_poly.coefficient(2), 2);
@@ -385,7 +1897,7 @@ mod tests {
         let rem_class_ring_poly = IntPoly::new(
             &mut vec![1, 13, 5, 10],
             Modulus::Some(5)
-        );
+        ).unwrap();
         assert_eq!(rem_class_ring_poly.coefficient(0), 1);
         assert_eq!(rem_class_ring_poly.coefficient(1), 3);
         assert_eq!(rem_class_ring_poly.coefficient(2), 0);
@@ -406,7 +1918,7 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(poly1.deg(), 3);
 
         println!("Testing integer polynomial with trailing zero coefficients.");
@@ -414,7 +1926,7 @@ mod tests {
         let poly2 = IntPoly::new(
             &mut vec![426, 1, 0],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(poly2.deg(), 1);
 
         println!("Testing remainder class ring polynomial with no trailing zero coefficients.");
@@ -422,7 +1934,7 @@ mod tests {
         let poly3 = IntPoly::new(
             &mut vec![1, 1, 1, 2],
             Modulus::Some(5)
-        );
+        ).unwrap();
         assert_eq!(poly3.deg(), 3);
 
         println!("Testing remainder class ring polynomial with trailing zero coefficients.");
@@ -430,12 +1942,12 @@ mod tests {
         let poly4 = IntPoly::new(
             &mut vec![1, 4, 5, 10],
             Modulus::Some(5)
-        );
+        ).unwrap();
         assert_eq!(poly4.deg(), 1);
 
         println!("Testing integer zero polynomial.");
 
-        let zero_poly = zero_polynomial(Modulus::None);
+        let zero_poly = zero_polynomial(Modulus::None).unwrap();
         assert_eq!(zero_poly.deg(), -1);
     }
 
@@ -446,11 +1958,11 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         let poly2 = IntPoly::new(
             &mut vec![425, 425, 425, 425],
             Modulus::None
-        );
+        ).unwrap();
 
         let result_12_poly = add_poly(&poly1, &poly2).unwrap();
 
@@ -459,7 +1971,7 @@ mod tests {
             IntPoly::new(
                 &mut vec![426, 426, 426, 426],
                 Modulus::None
-            )
+            ).unwrap()
         );
 
         println!("Adding two integer polynomials with trailing zeros in the sum.");
@@ -467,11 +1979,11 @@ mod tests {
         let poly3 = IntPoly::new(
             &mut vec![1, 1, 1, 426],
             Modulus::None
-        );
+        ).unwrap();
         let poly4 = IntPoly::new(
             &mut vec![1, 1, 1, -426],
             Modulus::None
-        );
+        ).unwrap();
 
         let result_34_poly = add_poly(&poly3, &poly4).unwrap();
 
@@ -480,7 +1992,7 @@ mod tests {
             IntPoly::new(
                 &mut vec![2, 2, 2],
                 Modulus::None
-            )
+            ).unwrap()
         );
 
         println!("Adding two remainder class ring polynomials with no trailing zeros in the sum.");
@@ -488,11 +2000,11 @@ mod tests {
         let poly5 = IntPoly::new(
             &mut vec![1, 1, 1, 1],
             Modulus::Some(5)
-        );
+        ).unwrap();
         let poly6 = IntPoly::new(
             &mut vec![2, 2, 2, 2],
             Modulus::Some(5)
-        );
+        ).unwrap();
 
         let result_56_poly = add_poly(&poly5, &poly6).unwrap();
 
@@ -501,7 +2013,7 @@ mod tests {
             IntPoly::new(
                 &mut vec![3, 3, 3, 3],
                 Modulus::Some(5)
-            )
+            ).unwrap()
         );
 
         println!("Adding two remainder class ring polynomials with trailing zeros in the sum.");
@@ -509,11 +2021,11 @@ mod tests {
         let poly7 = IntPoly::new(
             &mut vec![2, 1, 1, 1],
             Modulus::Some(426)
-        );
+        ).unwrap();
         let poly8 = IntPoly::new(
             &mut vec![425, 425, 425, 425],
             Modulus::Some(426)
-        );
+        ).unwrap();
 
         let result_78_poly = add_poly(&poly7, &poly8).unwrap();
 
@@ -522,7 +2034,7 @@ mod tests {
             IntPoly::new(
                 &mut vec![427],
                 Modulus::Some(426)
-            )
+            ).unwrap()
         );
     }
 
@@ -533,11 +2045,11 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         let poly2 = IntPoly::new(
             &mut vec![425, 425, 425, 425],
             Modulus::Some(426)
-        );
+        ).unwrap();
 
         let result_12_poly = add_poly(&poly1, &poly2);
 
@@ -556,14 +2068,14 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 1, 426],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(
             subtract_poly(&poly1, &poly1),
             Ok(
                 IntPoly::new(
                     &mut vec![],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
 
@@ -572,14 +2084,14 @@ mod tests {
         let poly2 = IntPoly::new(
             &mut vec![0, 2, 427, 424],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(
             subtract_poly(&poly1, &poly2),
             Ok(
                 IntPoly::new(
                     &mut vec![1, -1, -426, 2],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
     }
@@ -590,13 +2102,13 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(
             poly1.scale(426),
             IntPoly::new(
                 &mut vec![426, 426, 426],
                 Modulus::None
-            )
+            ).unwrap()
         );
 
         println!("Scale a polynomial with a negative number.");
@@ -605,7 +2117,7 @@ mod tests {
             IntPoly::new(
                 &mut vec![-426, -426, -426],
                 Modulus::None
-            )
+            ).unwrap()
         );
 
         println!("Scale a polynomial with 0.");
@@ -614,10 +2126,49 @@ mod tests {
             IntPoly::new(
                 &mut vec![],
                 Modulus::None
-            )
+            ).unwrap()
         )
     }
 
+    #[test]
+    fn in_place_arithmetic_test() {
+        println!("Asserting that in-place addition agrees with add_poly.");
+
+        let poly_pairs = vec![
+            (vec![1, 1, 1, 426], vec![1, 1, 1, -426], Modulus::None),
+            (vec![1], vec![0, 0, 426], Modulus::None),
+            (vec![2, 1, 1, 1], vec![425, 425, 425, 425], Modulus::Some(426)),
+            (vec![], vec![3, 4], Modulus::Some(5))
+        ];
+
+        for (coeffs1, coeffs2, md) in poly_pairs {
+            let poly1 = IntPoly::new(&mut coeffs1.clone(), md).unwrap();
+            let poly2 = IntPoly::new(&mut coeffs2.clone(), md).unwrap();
+
+            let mut sum = poly1.clone();
+            assert_eq!(sum.add_assign(&poly2), Ok(()));
+            assert_eq!(Ok(sum), add_poly(&poly1, &poly2));
+        }
+
+        println!("Asserting that in-place addition of polynomials with mismatching moduli fails.");
+
+        let mut poly3 = IntPoly::new(&mut vec![1, 426], Modulus::None).unwrap();
+
+        assert_eq!(
+            poly3.add_assign(&IntPoly::new(&mut vec![1], Modulus::Some(5)).unwrap()),
+            Err(PolynomialError::ModulusMismatchError(Modulus::None, Modulus::Some(5)))
+        );
+        assert_eq!(poly3, IntPoly::new(&mut vec![1, 426], Modulus::None).unwrap());
+
+        println!("Asserting that in-place scaling agrees with scale.");
+
+        for factor in [426, -1, 0] {
+            let mut scaled = poly3.clone();
+            scaled.scale_assign(factor);
+            assert_eq!(scaled, poly3.scale(factor));
+        }
+    }
+
     #[test]
     fn test_sum_of_polys() {
         println!("Testing with four integer polynomials.");
@@ -625,19 +2176,19 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 1, 426],
             Modulus::None
-        );
+        ).unwrap();
         let poly2 = IntPoly::new(
             &mut vec![2, 2, 2],
             Modulus::None
-        );
+        ).unwrap();
         let poly3 = IntPoly::new(
             &mut vec![1, 1, 0],
             Modulus::None
-        );
+        ).unwrap();
         let poly4 = IntPoly::new(
             &mut vec![0, 0, 0],
             Modulus::None
-        );
+        ).unwrap();
 
         let poly_vec = vec![poly1, poly2, poly3, poly4];
 
@@ -649,7 +2200,7 @@ mod tests {
                 IntPoly::new(
                     &mut vec![4, 4, 428],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
 
@@ -657,7 +2208,7 @@ mod tests {
 
         assert_eq!(
             sum_of_polys(&vec![]),
-            Ok(zero_polynomial(Modulus::None))
+            Ok(zero_polynomial(Modulus::None).unwrap())
         )
     }
 
@@ -668,12 +2219,12 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 2, 1],
             Modulus::None
-        );
+        ).unwrap();
 
         let poly2 = IntPoly::new(
             &mut vec![0, 4, 0, 1],
             Modulus::None
-        );
+        ).unwrap();
 
         assert_eq!(
             multiply_poly(&poly1, &poly2),
@@ -681,7 +2232,7 @@ mod tests {
                 IntPoly::new(
                     &mut vec![0, 4, 8, 5, 2, 1],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
 
@@ -692,11 +2243,67 @@ mod tests {
                 IntPoly::new(
                     &mut vec![0, 4, 8, 5, 2, 1],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
     }
 
+    #[test]
+    fn test_multiply_poly_checked() {
+        println!("Asserting that checked multiplication agrees with multiply_poly if no overflow occurs.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![1, 2, 1],
+            Modulus::None
+        ).unwrap();
+        let poly2 = IntPoly::new(
+            &mut vec![0, 4, 0, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            multiply_poly_checked(&poly1, &poly2),
+            multiply_poly(&poly1, &poly2)
+        );
+
+        println!("Asserting that an overflowing coefficient product is detected.");
+
+        let big_poly = IntPoly::new(
+            &mut vec![1, 100000],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            multiply_poly_checked(&big_poly, &big_poly),
+            Err(PolynomialError::OverflowError)
+        );
+
+        println!("Asserting that an overflowing coefficient sum is detected.");
+
+        let sum_poly = IntPoly::new(
+            &mut vec![i32::MAX / 2 + 1, i32::MAX / 2 + 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            multiply_poly_checked(&sum_poly, &IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap()),
+            Err(PolynomialError::OverflowError)
+        );
+
+        println!("Asserting that products over Z/qZ are reduced instead of reported as overflows.");
+
+        let mod_poly = IntPoly::new(
+            &mut vec![99999, 99999],
+            Modulus::Some(100003)
+        ).unwrap();
+
+        // 99999 = -4 mod 100003, so the square is 16 + 32X + 16X^2
+        assert_eq!(
+            multiply_poly_checked(&mod_poly, &mod_poly),
+            Ok(IntPoly::new(&mut vec![16, 32, 16], Modulus::Some(100003)).unwrap())
+        );
+    }
+
     #[test]
     fn test_product_of_polys() {
         println!("Testing with three integer polynomials.");
@@ -704,15 +2311,15 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![0, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         let poly2 = IntPoly::new(
             &mut vec![1, 1, 1],
             Modulus::None
-        );
+        ).unwrap();
         let poly3 = IntPoly::new(
             &mut vec![1, 1, 2],
             Modulus::None
-        );
+        ).unwrap();
 
         assert_eq!(
             product_of_polys(&vec![poly1, poly2, poly3]),
@@ -720,7 +2327,7 @@ mod tests {
                 IntPoly::new(
                     &mut vec![0, 1, 3, 6, 7, 5, 2],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
     }
@@ -732,14 +2339,14 @@ mod tests {
         let poly = IntPoly::new(
             &mut vec![1, 1],
             Modulus::None
-        );
+        ).unwrap();
         assert_eq!(
             poly_power(&poly, 2),
             Ok(
                 IntPoly::new(
                     &mut vec![1, 2, 1],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
 
@@ -751,7 +2358,7 @@ mod tests {
                 IntPoly::new(
                     &mut vec![1, 3, 3, 1],
                     Modulus::None
-                )
+                ).unwrap()
             )
         );
 
@@ -760,11 +2367,11 @@ mod tests {
         let rem_class_poly = IntPoly::new(
             &mut vec![1, 1],
             Modulus::Some(5)
-        );
+        ).unwrap();
 
         assert_eq!(
             poly_power(&rem_class_poly, 0),
-            Ok(one_polynomial(Modulus::Some(5)))
+            Ok(one_polynomial(Modulus::Some(5)).unwrap())
         );
     }
 
@@ -773,8 +2380,1282 @@ mod tests {
         let poly1 = IntPoly::new(
             &mut vec![1, 2, 1, 0],
             Modulus::None
-        );
+        ).unwrap();
 
         assert_eq!(poly1.to_string(), "1X^0 + 2X^1 + 1X^2");
+
+        println!("Asserting that monomials with coefficient 0 are skipped.");
+
+        let poly2 = IntPoly::new(
+            &mut vec![0, -3, 0, 426],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly2.to_string(), "-3X^1 + 426X^3");
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        let poly1 = IntPoly::new(
+            &mut vec![1, -2, 0, 426],
+            Modulus::None
+        ).unwrap();
+
+        println!("Asserting that Display uses the same formatting as to_string.");
+
+        assert_eq!(format!("{}", poly1), "1X^0 + -2X^1 + 426X^3");
+        assert_eq!(format!("{}", zero_polynomial(Modulus::None).unwrap()), "0");
+
+        println!("Round-tripping polynomials through Display and FromStr.");
+
+        assert_eq!(format!("{}", poly1).parse::<IntPoly>(), Ok(poly1));
+        assert_eq!("0".parse::<IntPoly>(), Ok(zero_polynomial(Modulus::None).unwrap()));
+        assert_eq!(
+            "1+X^2".parse::<IntPoly>(),
+            Err(PolynomialError::ParseError(String::from("1")))
+        );
+
+        println!("Parsing with unusual whitespace and repeated exponents.");
+
+        assert_eq!(
+            " 3X^2+1X^0 +   -1X^2 ".parse::<IntPoly>(),
+            Ok(IntPoly::new(&mut vec![1, 0, 2], Modulus::None).unwrap())
+        );
+
+        println!("Asserting that malformed monomials are rejected.");
+
+        assert_eq!("".parse::<IntPoly>(), Err(PolynomialError::ParseError(String::from(""))));
+        assert_eq!("2X^-1".parse::<IntPoly>(), Err(PolynomialError::ParseError(String::from("2X^-1"))));
+        assert_eq!("aX^1".parse::<IntPoly>(), Err(PolynomialError::ParseError(String::from("aX^1"))));
+
+        println!("Asserting that huge exponents and overflowing coefficient sums are rejected.");
+
+        assert_eq!(
+            "1X^4000000000".parse::<IntPoly>(), 
+            Err(PolynomialError::ParseError(String::from("1X^4000000000")))
+        );
+        assert_eq!("1X^1000000".parse::<IntPoly>().map(|p| p.deg()), Ok(1_000_000));
+        assert_eq!(
+            "2147483647X^1 + 1X^1".parse::<IntPoly>(), 
+            Err(PolynomialError::OverflowError)
+        );
+    }
+
+    #[test]
+    fn test_string_representation_with_var() {
+        let poly1 = IntPoly::new(
+            &mut vec![1, 2, 1],
+            Modulus::None
+        ).unwrap();
+
+        println!("Asserting that the passed variable name is used.");
+
+        assert_eq!(poly1.to_string_with_var("t"), "1 + 2t + t^2");
+
+        println!("Asserting that coefficients 1 and -1 and exponents 0 and 1 are omitted, zero monomials are skipped.");
+
+        let poly2 = IntPoly::new(&mut vec![-1, -1, 0, -3, 1], Modulus::None).unwrap();
+        assert_eq!(poly2.to_string_with_var("y"), "-1 + -y + -3y^3 + y^4");
+        assert_eq!(IntPoly::new(&mut vec![0, 1], Modulus::None).unwrap().to_string_with_var("X"), "X");
+        assert_eq!(IntPoly::new(&mut vec![426], Modulus::None).unwrap().to_string_with_var("X"), "426");
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().to_string_with_var("t"), "0");
+    }
+
+    #[test]
+    fn test_sign_variations() {
+        println!("Counting sign variations of X^3 - X^2 - X + 1.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![1, -1, -1, 1],
+            Modulus::None
+        ).unwrap();
+        assert_eq!(poly1.sign_variations(), 2);
+
+        println!("Asserting that zero coefficients are skipped.");
+
+        let poly2 = IntPoly::new(
+            &mut vec![-426, 0, 0, 1],
+            Modulus::None
+        ).unwrap();
+        assert_eq!(poly2.sign_variations(), 1);
+
+        println!("Counting sign variations of a polynomial with positive coefficients.");
+
+        let poly3 = IntPoly::new(
+            &mut vec![1, 2, 0, 426],
+            Modulus::None
+        ).unwrap();
+        assert_eq!(poly3.sign_variations(), 0);
+
+        println!("Counting sign variations of the zero polynomial.");
+
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().sign_variations(), 0);
+    }
+
+    #[test]
+    fn test_evaluate_at_matrix() {
+        println!("Evaluating X^2 + 1 at a 2x2 matrix.");
+
+        let a = Matrix::new(
+            vec![
+                vec![1.0, 2.0],
+                vec![3.0, 4.0]
+            ]
+        ).unwrap();
+
+        assert_eq!(
+            IntPoly::new(&mut vec![1, 0, 1], Modulus::None).unwrap().evaluate_at_matrix(&a),
+            Matrix::new(
+                vec![
+                    vec![8.0, 10.0],
+                    vec![15.0, 23.0]
+                ]
+            )
+        );
+
+        println!("Asserting that the matrix is a root of its characteristic polynomial (Cayley-Hamilton).");
+
+        let p_a = a.characteristic_polynomial().unwrap().evaluate_at_matrix(&a).unwrap();
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(p_a.entry(i, j).abs() < 1e-4);
+            }
+        }
+
+        println!("Asserting that a polynomial cannot be evaluated at a non-square matrix.");
+
+        assert_eq!(
+            one_polynomial(Modulus::None).unwrap().evaluate_at_matrix(&Matrix::new(vec![vec![1.0, 2.0]]).unwrap()),
+            Err(MatrixError::NotSquareError)
+        );
+    }
+
+    #[test]
+    fn test_content_primitive_part() {
+        println!("Computing content and primitive part of an integer polynomial.");
+
+        let poly = IntPoly::new(
+            &mut vec![6, -4, -426],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly.content(), 2);
+        assert_eq!(
+            poly.primitive_part(),
+            IntPoly::new(
+                &mut vec![-3, 2, 213],
+                Modulus::None
+            ).unwrap()
+        );
+
+        println!("Computing content of the zero polynomial.");
+
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().content(), 0);
+    }
+
+    #[test]
+    fn test_integer_poly_gcd() {
+        println!("Computing the gcd of X^2 - 1 and X^2 - 2X + 1.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![-1, 0, 1],
+            Modulus::None
+        ).unwrap();
+        let poly2 = IntPoly::new(
+            &mut vec![1, -2, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            integer_poly_gcd(&poly1, &poly2),
+            IntPoly::new(
+                &mut vec![-1, 1],
+                Modulus::None
+            ).unwrap()
+        );
+
+        println!("Computing the gcd of polynomials whose leading coefficients do not divide each other.");
+
+        // (2X + 1)(X + 3) and (2X + 1)(3X - 1)
+        let poly3 = IntPoly::new(
+            &mut vec![3, 7, 2],
+            Modulus::None
+        ).unwrap();
+        let poly4 = IntPoly::new(
+            &mut vec![-1, 1, 6],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            integer_poly_gcd(&poly3, &poly4),
+            IntPoly::new(
+                &mut vec![1, 2],
+                Modulus::None
+            ).unwrap()
+        );
+
+        println!("Computing the gcd of coprime polynomials.");
+
+        let poly5 = IntPoly::new(
+            &mut vec![1, 0, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(integer_poly_gcd(&poly1, &poly5), one_polynomial(Modulus::None).unwrap());
+    }
+
+    #[test]
+    fn test_newton_identities() {
+        println!("Converting the power sums of 1, 2, 3 into elementary symmetric polynomials.");
+
+        assert_eq!(power_sums_to_elementary(&[6, 14, 36]), vec![6, 11, 6]);
+
+        println!("Converting the elementary symmetric polynomials of 1, 2, 3 into power sums.");
+
+        assert_eq!(elementary_to_power_sums(&[6, 11, 6]), vec![6, 14, 36]);
+
+        println!("Converting the power sums of -2, 3, 5, 1 and back.");
+
+        let power_sums = vec![7, 39, 145, 723];
+        let elementary = vec![7, 5, -31, -30];
+
+        assert_eq!(power_sums_to_elementary(&power_sums), elementary);
+        assert_eq!(elementary_to_power_sums(&elementary), power_sums);
+
+        println!("Converting empty sequences.");
+
+        assert_eq!(power_sums_to_elementary(&[]), vec![]);
+        assert_eq!(elementary_to_power_sums(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        println!("Evaluating an integer polynomial.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![1, -2, 3],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly1.evaluate(0), 1);
+        assert_eq!(poly1.evaluate(2), 9);
+        assert_eq!(poly1.evaluate(-3), 34);
+
+        println!("Evaluating a remainder class ring polynomial.");
+
+        let poly2 = IntPoly::new(
+            &mut vec![1, -2, 3],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(poly2.evaluate(2), 4);
+        assert_eq!(poly2.evaluate(-3), 4);
+
+        println!("Evaluating a remainder class ring polynomial at a large argument without overflow.");
+
+        let poly3 = IntPoly::new(
+            &mut vec![426, 0, 1],
+            Modulus::Some(10007)
+        ).unwrap();
+
+        // 10^9 = 490 mod 10007 and 490^2 + 426 = 358 mod 10007
+        assert_eq!(poly3.evaluate(1_000_000_000), 358);
+        assert_eq!(poly3.evaluate(1_000_000_000), poly3.evaluate_mod(1_000_000_000).unwrap());
+
+        println!("Evaluating a polynomial over Z/65537Z, where products of residues exceed i32::MAX.");
+
+        let poly4 = IntPoly::new(&mut vec![3, 60000], Modulus::Some(65537)).unwrap();
+
+        // 60000^2 + 3 = 3600000003 = 52593 mod 65537
+        assert_eq!(poly4.evaluate(60000), 52593);
+        assert_eq!(poly4.evaluate(-5537), 52593);
+
+        println!("Evaluating the zero polynomial.");
+
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().evaluate(426), 0);
+        assert_eq!(zero_polynomial(Modulus::Some(5)).unwrap().evaluate(-426), 0);
+    }
+
+    #[test]
+    fn test_integer_roots() {
+        println!("Computing the integer roots of X^3 - 6X^2 + 11X - 6.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![-6, 11, -6, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly1.integer_roots(), vec![1, 2, 3]);
+
+        println!("Computing the integer roots of X^4 + 2X^3 - 3X^2 = X^2 (X + 3)(X - 1).");
+
+        let poly2 = IntPoly::new(
+            &mut vec![0, 0, -3, 2, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly2.integer_roots(), vec![-3, 0, 1]);
+
+        println!("Computing the integer roots of polynomials with large coefficients without overflow.");
+
+        assert_eq!(IntPoly::new(&mut vec![-60000, 0, 1], Modulus::None).unwrap().integer_roots(), vec![]);
+        assert_eq!(IntPoly::new(&mut vec![-60025, 0, 1], Modulus::None).unwrap().integer_roots(), vec![-245, 245]);
+        assert_eq!(IntPoly::new(&mut vec![i32::MAX, -1], Modulus::None).unwrap().integer_roots(), vec![i32::MAX]);
+        assert_eq!(IntPoly::new(&mut vec![i32::MIN, 1], Modulus::None).unwrap().integer_roots(), vec![]);
+
+        println!("Asserting that polynomials without integer roots yield no roots.");
+
+        assert_eq!(IntPoly::new(&mut vec![1, 0, 1], Modulus::None).unwrap().integer_roots(), vec![]);
+        assert_eq!(IntPoly::new(&mut vec![1, 2], Modulus::None).unwrap().integer_roots(), vec![]);
+
+        println!("Asserting that constants and the zero polynomial yield no roots.");
+
+        assert_eq!(one_polynomial(Modulus::None).unwrap().integer_roots(), vec![]);
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().integer_roots(), vec![]);
+    }
+
+    #[test]
+    fn test_derivative() {
+        println!("Computing the derivative of an integer polynomial.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![426, 3, -2, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            poly1.derivative(),
+            IntPoly::new(
+                &mut vec![3, -4, 3],
+                Modulus::None
+            ).unwrap()
+        );
+
+        println!("Computing the derivative of a remainder class ring polynomial.");
+
+        // 1 + X + X^5 over Z/5Z has derivative 1 + 5X^4 = 1
+        let poly2 = IntPoly::new(
+            &mut vec![1, 1, 0, 0, 0, 1],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(poly2.derivative(), one_polynomial(Modulus::Some(5)).unwrap());
+
+        println!("Computing the derivative of a constant.");
+
+        assert_eq!(
+            IntPoly::new(&mut vec![426], Modulus::Some(7)).unwrap().derivative(),
+            zero_polynomial(Modulus::Some(7)).unwrap()
+        );
+
+        println!("Computing the derivative of the zero polynomial.");
+
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().derivative(), zero_polynomial(Modulus::None).unwrap());
+
+        println!("Asserting that the coefficients of derivatives over Z/qZ are reduced.");
+
+        // 3X^2 + 4X^3 over Z/7Z has derivative 6X + 12X^2 = 6X + 5X^2
+        let derivative3 = IntPoly::new(&mut vec![0, 0, 3, 4], Modulus::Some(7)).unwrap().derivative();
+        assert!(derivative3.is_reduced());
+        assert_eq!(derivative3.coefficient(1), 6);
+        assert_eq!(derivative3.coefficient(2), 5);
+
+        println!("Computing the derivative of X^3000 over a large modulus without overflow.");
+
+        let mut coeffs4 = vec![0; 3000];
+        coeffs4.push(999_999);
+        let derivative4 = IntPoly::new(&mut coeffs4, Modulus::Some(1_000_003)).unwrap().derivative();
+        // 3000 * 999999 = 3000 * (-4) = -12000 = 988003 mod 1000003
+        assert_eq!(derivative4.leading_coefficient(), 988_003);
+        assert_eq!(derivative4.deg(), 2999);
+    }
+
+    #[test]
+    fn test_divide_poly() {
+        println!("Dividing X^2 - 1 by X - 1 over the integers.");
+
+        let poly1 = IntPoly::new(
+            &mut vec![-1, 0, 1],
+            Modulus::None
+        ).unwrap();
+        let poly2 = IntPoly::new(
+            &mut vec![-1, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            divide_poly(&poly1, &poly2),
+            Ok((
+                IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap(),
+                zero_polynomial(Modulus::None).unwrap()
+            ))
+        );
+
+        println!("Dividing with remainder over the integers.");
+
+        // X^3 + 2X + 426 = X * (X^2 + 1) + (X + 426)
+        let poly3 = IntPoly::new(
+            &mut vec![426, 2, 0, 1],
+            Modulus::None
+        ).unwrap();
+        let poly4 = IntPoly::new(
+            &mut vec![1, 0, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(
+            divide_poly(&poly3, &poly4),
+            Ok((
+                IntPoly::new(&mut vec![0, 1], Modulus::None).unwrap(),
+                IntPoly::new(&mut vec![426, 1], Modulus::None).unwrap()
+            ))
+        );
+
+        println!("Asserting that non-exact division over the integers fails.");
+
+        assert_eq!(
+            divide_poly(&poly1, &IntPoly::new(&mut vec![1, 2], Modulus::None).unwrap()),
+            Err(PolynomialError::NonExactDivisionError)
+        );
+
+        println!("Dividing over Z/5Z, where the leading coefficient of the divisor is inverted.");
+
+        // X^2 + 2 = (3X + 1) * (2X + 1) + 1 over Z/5Z
+        let poly5 = IntPoly::new(
+            &mut vec![2, 0, 1],
+            Modulus::Some(5)
+        ).unwrap();
+        let poly6 = IntPoly::new(
+            &mut vec![1, 2],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(
+            divide_poly(&poly5, &poly6),
+            Ok((
+                IntPoly::new(&mut vec![1, 3], Modulus::Some(5)).unwrap(),
+                IntPoly::new(&mut vec![1], Modulus::Some(5)).unwrap()
+            ))
+        );
+
+        println!("Asserting that division by the zero polynomial fails.");
+
+        assert_eq!(
+            divide_poly(&poly1, &zero_polynomial(Modulus::None).unwrap()),
+            Err(PolynomialError::DivisionByZeroError)
+        );
+    }
+
+    #[test]
+    fn test_poly_gcd() {
+        println!("Computing the gcd of two polynomials with a common factor over Z/5Z.");
+
+        // (X + 1)(X + 2) and (X + 1)(X + 3)
+        let poly1 = IntPoly::new(
+            &mut vec![2, 3, 1],
+            Modulus::Some(5)
+        ).unwrap();
+        let poly2 = IntPoly::new(
+            &mut vec![3, 4, 1],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(
+            poly_gcd(&poly1, &poly2),
+            Ok(IntPoly::new(&mut vec![1, 1], Modulus::Some(5)).unwrap())
+        );
+
+        println!("Computing the gcd of coprime polynomials over Z/5Z.");
+
+        let poly3 = IntPoly::new(
+            &mut vec![4, 1],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(poly_gcd(&poly1, &poly3), Ok(one_polynomial(Modulus::Some(5)).unwrap()));
+
+        println!("Asserting that integer polynomials are rejected.");
+
+        let poly4 = IntPoly::new(
+            &mut vec![1, 1],
+            Modulus::None
+        ).unwrap();
+
+        assert_eq!(poly_gcd(&poly4, &poly4), Err(PolynomialError::NotAFieldError(Modulus::None)));
+
+        println!("Asserting that polynomials with mismatching moduli are rejected.");
+
+        assert_eq!(
+            poly_gcd(&poly1, &poly4),
+            Err(PolynomialError::ModulusMismatchError(Modulus::Some(5), Modulus::None))
+        );
+    }
+
+    #[test]
+    fn test_squarefree_part() {
+        println!("Computing the squarefree part of (X - 1)^2 (X - 2) over Z/5Z.");
+
+        // (X - 1)^2 (X - 2) = X^3 - 4X^2 + 5X - 2
+        let poly = IntPoly::new(
+            &mut vec![-2, 5, -4, 1],
+            Modulus::Some(5)
+        ).unwrap();
+
+        // (X - 1)(X - 2) = X^2 - 3X + 2
+        assert_eq!(
+            poly.squarefree_part(),
+            Ok(IntPoly::new(&mut vec![2, 2, 1], Modulus::Some(5)).unwrap())
+        );
+
+        println!("Asserting that a squarefree polynomial is its own squarefree part.");
+
+        let squarefree_poly = IntPoly::new(
+            &mut vec![2, 2, 1],
+            Modulus::Some(5)
+        ).unwrap();
+
+        assert_eq!(squarefree_poly.squarefree_part(), Ok(squarefree_poly.clone()));
+
+        println!("Asserting that integer polynomials are rejected.");
+
+        assert_eq!(
+            one_polynomial(Modulus::None).unwrap().squarefree_part(),
+            Err(PolynomialError::NotAFieldError(Modulus::None))
+        );
+    }
+
+    #[test]
+    fn test_palindromic() {
+        println!("Asserting that 1 + 2X + 2X^2 + X^3 is palindromic.");
+        let p = IntPoly::new(&mut vec![1, 2, 2, 1], Modulus::None).unwrap();
+        assert!(p.is_palindromic());
+        assert!(!p.is_anti_palindromic());
+
+        println!("Asserting that 1 + 2X + X^2 + X^3 is not palindromic.");
+        let q = IntPoly::new(&mut vec![1, 2, 1, 1], Modulus::None).unwrap();
+        assert!(!q.is_palindromic());
+        assert_eq!(q.reverse(), IntPoly::new(&mut vec![1, 1, 2, 1], Modulus::None).unwrap());
+
+        println!("Asserting that X + X^2 is not palindromic (its reverse has lower degree).");
+        let r = IntPoly::new(&mut vec![0, 1, 1], Modulus::None).unwrap();
+        assert_eq!(r.reverse(), IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap());
+        assert!(!r.is_palindromic());
+
+        println!("Asserting that 1 - X^2 is anti-palindromic, also over Z/5Z as 1 + 4X^2.");
+        assert!(IntPoly::new(&mut vec![1, 0, -1], Modulus::None).unwrap().is_anti_palindromic());
+        assert!(IntPoly::new(&mut vec![1, 0, 4], Modulus::Some(5)).unwrap().is_anti_palindromic());
+    }
+
+    #[test]
+    fn test_reduced() {
+        let p = IntPoly::new(&mut vec![1, 13], Modulus::Some(5)).unwrap();
+
+        println!("Asserting that 1 + 13X over Z/5Z is not reduced, but its reduced form is.");
+        assert!(!p.is_reduced());
+        assert!(p.reduced().is_reduced());
+        assert_eq!(p.reduced(), IntPoly::new(&mut vec![1, 3], Modulus::Some(5)).unwrap());
+
+        println!("Asserting that negative coefficients are reduced into {{0, ..., q-1}}.");
+        assert_eq!(
+            IntPoly::new(&mut vec![-1, -7], Modulus::Some(5)).unwrap().reduced(), 
+            IntPoly::new(&mut vec![4, 3], Modulus::Some(5)).unwrap()
+        );
+
+        println!("Asserting that integer polynomials are always reduced.");
+        let q = IntPoly::new(&mut vec![-426, 13], Modulus::None).unwrap();
+        assert!(q.is_reduced());
+        assert_eq!(q.reduced(), q);
+    }
+
+    #[test]
+    fn test_coefficient_in() {
+        let p = IntPoly::new(&mut vec![5, 3, -1], Modulus::Some(7)).unwrap();
+
+        println!("Asserting that the mod-7 coefficient 5 is 5 under least non-negative and -2 under symmetric representatives.");
+        assert_eq!(p.coefficient_in(0, Representatives::LeastNonNegative), 5);
+        assert_eq!(p.coefficient_in(0, Representatives::Symmetric), -2);
+
+        println!("Reducing further coefficients of the polynomial.");
+        assert_eq!(p.coefficient_in(1, Representatives::Symmetric), 3);
+        assert_eq!(p.coefficient_in(2, Representatives::LeastNonNegative), 6);
+        assert_eq!(p.coefficient_in(2, Representatives::Symmetric), -1);
+        assert_eq!(p.coefficient_in(426, Representatives::Symmetric), 0);
+
+        println!("Asserting that for even moduli, q/2 is a symmetric representative.");
+        let q = IntPoly::new(&mut vec![3, 2], Modulus::Some(6)).unwrap();
+        assert_eq!(q.coefficient_in(0, Representatives::Symmetric), 3);
+        assert_eq!(q.coefficient_in(1, Representatives::Symmetric), 2);
+
+        println!("Asserting that integer coefficients are not reduced.");
+        let r = IntPoly::new(&mut vec![-426], Modulus::None).unwrap();
+        assert_eq!(r.coefficient_in(0, Representatives::Symmetric), -426);
+    }
+
+    #[test]
+    fn test_scaling_operator() {
+        let p = IntPoly::new(&mut vec![1, -2, 426], Modulus::None).unwrap();
+
+        println!("Asserting that &p * 3 and 3 * &p equal p.scale(3).");
+        assert_eq!(&p * 3, p.scale(3));
+        assert_eq!(3 * &p, p.scale(3));
+
+        println!("Asserting that scaling by 0 yields the zero polynomial.");
+        let zero = 0; // a literal factor 0 would be rejected by clippy as an erasing operation
+        assert_eq!(&p * zero, IntPoly::new(&mut vec![], Modulus::None).unwrap());
+
+        println!("Asserting that scaling by the modulus yields the zero polynomial over Z/5Z.");
+        let q = IntPoly::new(&mut vec![1, 2], Modulus::Some(5)).unwrap();
+        assert_eq!(5 * &q, IntPoly::new(&mut vec![], Modulus::Some(5)).unwrap());
+    }
+
+    #[test]
+    fn test_chebyshev_first_kind() {
+        println!("Asserting that T_0 = 1, T_1 = X and T_2 = 2X^2 - 1.");
+
+        assert_eq!(chebyshev_first_kind(0), IntPoly::new(&mut vec![1], Modulus::None).unwrap());
+        assert_eq!(chebyshev_first_kind(1), IntPoly::new(&mut vec![0, 1], Modulus::None).unwrap());
+        assert_eq!(chebyshev_first_kind(2), IntPoly::new(&mut vec![-1, 0, 2], Modulus::None).unwrap());
+
+        println!("Asserting that T_4 = 8X^4 - 8X^2 + 1.");
+
+        assert_eq!(chebyshev_first_kind(4), IntPoly::new(&mut vec![1, 0, -8, 0, 8], Modulus::None).unwrap());
+    }
+
+    #[test]
+    fn test_legendre_polynomial() {
+        println!("Asserting that the scaled Legendre polynomials 2^n * P_n are computed correctly.");
+
+        assert_eq!(legendre_polynomial(0), IntPoly::new(&mut vec![1], Modulus::None).unwrap());
+        assert_eq!(legendre_polynomial(1), IntPoly::new(&mut vec![0, 2], Modulus::None).unwrap());
+        // P_2 = (3X^2 - 1)/2
+        assert_eq!(legendre_polynomial(2), IntPoly::new(&mut vec![-2, 0, 6], Modulus::None).unwrap());
+        // P_3 = (5X^3 - 3X)/2
+        assert_eq!(legendre_polynomial(3), IntPoly::new(&mut vec![0, -12, 0, 20], Modulus::None).unwrap());
+        // P_4 = (35X^4 - 30X^2 + 3)/8
+        assert_eq!(legendre_polynomial(4), IntPoly::new(&mut vec![6, 0, -60, 0, 70], Modulus::None).unwrap());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        println!("Interpolating the points (0, 1), (1, 3), (2, 0) over Z/7Z.");
+
+        let f = interpolate(&[(0, 1), (1, 3), (2, 0)], Modulus::Some(7)).unwrap();
+        assert!(f.deg() <= 2);
+        assert_eq!(f.evaluate(0), 1);
+        assert_eq!(f.evaluate(1), 3);
+        assert_eq!(f.evaluate(2), 0);
+
+        println!("Interpolating the values of a known polynomial reproduces it.");
+
+        let g = IntPoly::new(&mut vec![4, 0, 2, 1], Modulus::Some(11)).unwrap();
+        let points = (0..4).map(|x| (x, g.evaluate(x))).collect::<Vec<(i32, i32)>>();
+        assert_eq!(interpolate(&points, Modulus::Some(11)), Ok(g));
+
+        println!("Asserting that duplicate points and non-prime moduli are rejected.");
+
+        assert_eq!(interpolate(&[(1, 1), (8, 2)], Modulus::Some(7)), Err(PolynomialError::DuplicateSamplePointError));
+        assert_eq!(interpolate(&[(1, 1)], Modulus::Some(6)), Err(PolynomialError::NotAFieldError(Modulus::Some(6))));
+    }
+
+    #[test]
+    fn test_multiply_poly_evaluation() {
+        // seeded random number generator, so the test is deterministic
+        let mut rng = StdRng::seed_from_u64(426);
+
+        println!("Asserting that multiplication via evaluation agrees with multiply_poly on random inputs over Z/101Z.");
+        let md = Modulus::Some(101);
+        for _ in 0..20 {
+            let len1 = rng.gen_range(0..10);
+            let len2 = rng.gen_range(0..10);
+            let a = IntPoly::new(&mut (0..len1).map(|_| rng.gen_range(0..101)).collect(), md).unwrap();
+            let b = IntPoly::new(&mut (0..len2).map(|_| rng.gen_range(0..101)).collect(), md).unwrap();
+
+            assert_eq!(multiply_poly_evaluation(&a, &b).unwrap(), multiply_poly(&a, &b).unwrap().reduced());
+        }
+
+        println!("Asserting that the degree-sum bound is respected over Z/5Z.");
+        let c = IntPoly::new(&mut vec![1, 1, 1], Modulus::Some(5)).unwrap();
+        let d = IntPoly::new(&mut vec![2, 0, 1], Modulus::Some(5)).unwrap();
+        // degree 4 needs the 5 points 0, ..., 4 which all exist in Z/5Z
+        assert_eq!(multiply_poly_evaluation(&c, &d).unwrap(), multiply_poly(&c, &d).unwrap().reduced());
+        let e = IntPoly::new(&mut vec![0, 0, 0, 1], Modulus::Some(5)).unwrap();
+        assert_eq!(multiply_poly_evaluation(&c, &e), Err(PolynomialError::InsufficientSamplePointsError));
+
+        println!("Asserting that multiplication via evaluation works for large primes, where products of residues exceed i32::MAX.");
+        for p in [65537, 1_000_000_007, 2_147_483_629] {
+            let md = Modulus::Some(p);
+            let a = IntPoly::new(&mut vec![p - 1, 60000, p - 426, 1], md).unwrap();
+            let b = IntPoly::new(&mut vec![p - 2, p - 3], md).unwrap();
+            // (-1 + 60000X - 426X^2 + X^3) * (-2 - 3X) = 2 - 119997X - 179148X^2 + 1276X^3 - 3X^4
+            let expected = IntPoly::new(&mut vec![2, -119997, -179148, 1276, -3], md).unwrap();
+
+            assert_eq!(multiply_poly_evaluation(&a, &b).unwrap(), expected.reduced());
+        }
+    }
+
+    #[test]
+    fn test_evaluate_mod() {
+        let p = IntPoly::new(&mut vec![1, 0, 1], Modulus::Some(5)).unwrap();
+
+        println!("Asserting that X^2 + 1 over Z/5Z vanishes at 2 and 3.");
+        assert_eq!(p.evaluate_mod(2), Ok(0));
+        assert_eq!(p.evaluate_mod(3), Ok(0));
+
+        println!("Asserting that the results are canonical residues, also for negative arguments.");
+        assert_eq!(p.evaluate_mod(4), Ok(2));
+        assert_eq!(p.evaluate_mod(-1), Ok(2));
+
+        println!("Asserting that integer polynomials are rejected.");
+        let q = IntPoly::new(&mut vec![1, 0, 1], Modulus::None).unwrap();
+        assert_eq!(q.evaluate_mod(2), Err(PolynomialError::NoModulusError));
+    }
+
+    #[test]
+    fn test_random_poly() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(426);
+
+        println!("Asserting that random polynomials over Z/7Z have the requested degree and reduced coefficients.");
+        for degree in 0..20 {
+            let p = random_poly(degree, Modulus::Some(7), &mut rng).unwrap();
+            assert_eq!(p.deg(), degree as i32);
+            assert!(p.is_reduced());
+        }
+
+        println!("Asserting that random integer polynomials have the requested degree and bounded coefficients.");
+        for degree in 0..20 {
+            let p = random_poly(degree, Modulus::None, &mut rng).unwrap();
+            assert_eq!(p.deg(), degree as i32);
+            assert!((0..(degree+1)).all(|i| p.coefficient(i).abs() <= RANDOM_COEFFICIENT_BOUND));
+        }
+
+        println!("Asserting that random polynomials over Z/1Z are rejected.");
+        assert_eq!(random_poly(3, Modulus::Some(1), &mut rng), Err(PolynomialError::InvalidModulusError(1)));
+    }
+
+    #[test]
+    fn test_integrate() {
+        println!("Asserting that the antiderivative of 2 + 2X is 2X + X^2.");
+
+        let p = IntPoly::new(&mut vec![2, 2], Modulus::None).unwrap();
+        assert_eq!(p.integrate(), Ok(IntPoly::new(&mut vec![0, 2, 1], Modulus::None).unwrap()));
+        assert_eq!(p.integrate().unwrap().derivative(), p);
+
+        println!("Asserting that non-integer quotients over the integers are rejected.");
+
+        assert_eq!(IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap().integrate(), Err(PolynomialError::NonExactDivisionError));
+
+        println!("Integrating over Z/7Z, where 1/2 is 4.");
+
+        let q = IntPoly::new(&mut vec![3, 1, 5], Modulus::Some(7)).unwrap();
+        let q_integral = q.integrate().unwrap();
+        assert_eq!(q_integral, IntPoly::new(&mut vec![0, 3, 4, 4], Modulus::Some(7)).unwrap());
+        assert_eq!(q_integral.derivative().reduced(), q);
+
+        println!("Asserting that dividing a non-zero coefficient by a multiple of p is rejected.");
+
+        let r = IntPoly::new(&mut vec![0, 0, 1], Modulus::Some(3)).unwrap();
+        assert_eq!(r.integrate(), Err(PolynomialError::NonExactDivisionError));
+        let s = IntPoly::new(&mut vec![1, 0, 0, 1], Modulus::Some(3)).unwrap();
+        assert_eq!(s.integrate(), Ok(IntPoly::new(&mut vec![0, 1, 0, 0, 1], Modulus::Some(3)).unwrap()));
+
+        println!("Asserting that non-prime moduli are rejected.");
+
+        assert_eq!(
+            IntPoly::new(&mut vec![1], Modulus::Some(6)).unwrap().integrate(), 
+            Err(PolynomialError::NotAFieldError(Modulus::Some(6)))
+        );
+    }
+
+    #[test]
+    fn test_divides() {
+        let x_minus_one = IntPoly::new(&mut vec![-1, 1], Modulus::None).unwrap();
+
+        println!("Asserting that X - 1 divides X^2 - 1 but not X^2 + 1.");
+        assert_eq!(x_minus_one.divides(&IntPoly::new(&mut vec![-1, 0, 1], Modulus::None).unwrap()), Ok(true));
+        assert_eq!(x_minus_one.divides(&IntPoly::new(&mut vec![1, 0, 1], Modulus::None).unwrap()), Ok(false));
+
+        println!("Asserting that 2X does not divide X over the integers.");
+        let two_x = IntPoly::new(&mut vec![0, 2], Modulus::None).unwrap();
+        assert_eq!(two_x.divides(&IntPoly::new(&mut vec![0, 1], Modulus::None).unwrap()), Ok(false));
+
+        println!("Asserting that X + 1 divides X^2 + 1 over Z/2Z.");
+        let x_plus_one = IntPoly::new(&mut vec![1, 1], Modulus::Some(2)).unwrap();
+        assert_eq!(x_plus_one.divides(&IntPoly::new(&mut vec![1, 0, 1], Modulus::Some(2)).unwrap()), Ok(true));
+
+        println!("Asserting that the zero polynomial and mismatching moduli are rejected.");
+        assert_eq!(
+            zero_polynomial(Modulus::None).unwrap().divides(&x_minus_one), 
+            Err(PolynomialError::DivisionByZeroError)
+        );
+        assert_eq!(
+            x_plus_one.divides(&x_minus_one), 
+            Err(PolynomialError::ModulusMismatchError(Modulus::None, Modulus::Some(2)))
+        );
+    }
+
+    #[test]
+    fn test_synthetic_divide() {
+        let p = IntPoly::new(&mut vec![-1, 0, 1], Modulus::None).unwrap();
+
+        println!("Asserting that X^2 - 1 divided by X - 1 is X + 1 with remainder 0.");
+        assert_eq!(p.synthetic_divide(1), (IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap(), 0));
+
+        println!("Asserting that the remainder equals the value of the polynomial.");
+        let q = IntPoly::new(&mut vec![5, -3, 0, 2], Modulus::None).unwrap();
+        for root in -3..4 {
+            let (quotient, remainder) = q.synthetic_divide(root);
+            assert_eq!(remainder, q.evaluate(root));
+            assert_eq!(quotient.deg(), 2);
+        }
+
+        println!("Dividing over Z/5Z and dividing constants.");
+        let r = IntPoly::new(&mut vec![1, 0, 1], Modulus::Some(5)).unwrap();
+        assert_eq!(r.synthetic_divide(2), (IntPoly::new(&mut vec![2, 1], Modulus::Some(5)).unwrap(), 0));
+        assert_eq!(
+            IntPoly::new(&mut vec![426], Modulus::None).unwrap().synthetic_divide(1), 
+            (zero_polynomial(Modulus::None).unwrap(), 426)
+        );
+
+        println!("Dividing over Z/1000003Z, where products of the root and residues exceed i32::MAX.");
+        let s = IntPoly::new(&mut vec![1, 999_999], Modulus::Some(1_000_003)).unwrap();
+        for root in [5000, -5000, 1_000_003 + 5000] {
+            let (quotient, remainder) = s.synthetic_divide(root);
+            assert_eq!(quotient, IntPoly::new(&mut vec![999_999], Modulus::Some(1_000_003)).unwrap());
+            assert_eq!(remainder, s.evaluate_mod(root).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_count_real_roots_in() {
+        let p = IntPoly::new(&mut vec![-2, 0, 1], Modulus::None).unwrap();
+
+        println!("Asserting that X^2 - 2 has one root in (0, 2), none in (2, 3) and two in (-2, 2).");
+        assert_eq!(p.count_real_roots_in(0.0, 2.0), Ok(1));
+        assert_eq!(p.count_real_roots_in(2.0, 3.0), Ok(0));
+        assert_eq!(p.count_real_roots_in(-2.0, 2.0), Ok(2));
+
+        println!("Counting the roots of (X - 1)^2 (X + 3) (X^2 + 1), multiple roots are counted once.");
+        let q = product_of_polys(&vec![
+            IntPoly::new(&mut vec![-1, 1], Modulus::None).unwrap(),
+            IntPoly::new(&mut vec![-1, 1], Modulus::None).unwrap(),
+            IntPoly::new(&mut vec![3, 1], Modulus::None).unwrap(),
+            IntPoly::new(&mut vec![1, 0, 1], Modulus::None).unwrap()
+        ]).unwrap();
+        assert_eq!(q.count_real_roots_in(-10.0, 10.0), Ok(2));
+        assert_eq!(q.count_real_roots_in(0.0, 10.0), Ok(1));
+        assert_eq!(q.count_real_roots_in(-2.0, 0.5), Ok(0));
+
+        println!("Asserting that polynomials over Z/qZ and the zero polynomial are rejected.");
+        assert_eq!(
+            IntPoly::new(&mut vec![1, 1], Modulus::Some(5)).unwrap().count_real_roots_in(0.0, 1.0), 
+            Err(PolynomialError::NotAnIntegerPolynomialError(Modulus::Some(5)))
+        );
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().count_real_roots_in(0.0, 1.0), Err(PolynomialError::ZeroPolynomialError));
+    }
+
+    #[test]
+    fn taylor_coefficients_test() {
+        println!("Expanding X^2 around 1: X^2 = 1 + 2(X-1) + (X-1)^2.");
+        let square = IntPoly::new(&mut vec![0, 0, 1], Modulus::None).unwrap();
+        assert_eq!(square.taylor_coefficients(1), vec![1, 2, 1]);
+
+        println!("Expanding X^3 - 2X + 5 around -2, the coefficients are p^(k)(-2)/k!.");
+        let poly = IntPoly::new(&mut vec![5, -2, 0, 1], Modulus::None).unwrap();
+        assert_eq!(poly.taylor_coefficients(-2), vec![1, 10, -6, 1]);
+
+        println!("Expanding around 0 returns the coefficients themselves.");
+        assert_eq!(poly.taylor_coefficients(0), vec![5, -2, 0, 1]);
+
+        println!("Expanding over Z/7Z and expanding the zero polynomial.");
+        let poly_mod = IntPoly::new(&mut vec![0, 0, 1], Modulus::Some(7)).unwrap();
+        assert_eq!(poly_mod.taylor_coefficients(426), vec![1, 5, 1]);
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().taylor_coefficients(426), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn negative_coefficient_reduction_test() {
+        println!("Asserting that negative coefficients are reduced to non-negative residues.");
+
+        let poly = IntPoly::new(&mut vec![-1], Modulus::Some(5)).unwrap();
+        assert_eq!(poly.coefficient(0), 4);
+        assert_eq!(poly.leading_coefficient(), 4);
+
+        let poly2 = IntPoly::new(&mut vec![-426, 3, -2], Modulus::Some(5)).unwrap();
+        assert_eq!(poly2.coefficient(0), 4);
+        assert_eq!(poly2.coefficient(2), 3);
+
+        println!("Reducing the negative coefficients of -1 - 3X over several moduli.");
+
+        for (q, expected) in [(2, [1, 1]), (3, [2, 0]), (5, [4, 2]), (7, [6, 4]), (13, [12, 10])] {
+            let poly = IntPoly::new(&mut vec![-1, -3], Modulus::Some(q)).unwrap();
+            assert_eq!([poly.coefficient(0), poly.coefficient(1)], expected);
+        }
+
+        println!("Asserting that integer polynomials keep their negative coefficients.");
+
+        let poly3 = IntPoly::new(&mut vec![-1], Modulus::None).unwrap();
+        assert_eq!(poly3.coefficient(0), -1);
+    }
+
+    #[test]
+    fn has_repeated_roots_test() {
+        println!("Asserting that (X-1)^2 has a repeated root while (X-1)(X-2) does not.");
+
+        let double_root = IntPoly::new(&mut vec![1, -2, 1], Modulus::None).unwrap();
+        let simple_roots = IntPoly::new(&mut vec![2, -3, 1], Modulus::None).unwrap();
+        assert_eq!(double_root.has_repeated_roots(), Ok(true));
+        assert_eq!(simple_roots.has_repeated_roots(), Ok(false));
+
+        println!("Asserting that (X^2 + 1)^2 has repeated complex roots.");
+
+        let squared_irreducible = IntPoly::new(&mut vec![1, 0, 2, 0, 1], Modulus::None).unwrap();
+        assert_eq!(squared_irreducible.has_repeated_roots(), Ok(true));
+
+        println!("Checking polynomials over Z/5Z: X^5 - X has distinct roots while X^5 is a fifth power.");
+
+        assert_eq!(IntPoly::new(&mut vec![0, -1, 0, 0, 0, 1], Modulus::Some(5)).unwrap().has_repeated_roots(), Ok(false));
+        assert_eq!(IntPoly::new(&mut vec![0, 0, 0, 0, 0, 1], Modulus::Some(5)).unwrap().has_repeated_roots(), Ok(true));
+
+        println!("Checking constants and the zero polynomial.");
+
+        assert_eq!(IntPoly::new(&mut vec![426], Modulus::None).unwrap().has_repeated_roots(), Ok(false));
+        assert_eq!(zero_polynomial(Modulus::None).unwrap().has_repeated_roots(), Err(PolynomialError::ZeroPolynomialError));
+    }
+
+    #[test]
+    fn newton_interpolation_test() {
+        println!("Computing divided differences for samples of 2X^2 - 3X + 1.");
+
+        let points = [(0.0, 1.0), (1.0, 0.0), (3.0, 10.0)];
+        let coeffs = divided_differences(&points);
+        assert_eq!(coeffs, vec![1.0, -1.0, 2.0]);
+
+        println!("Asserting that the Newton form recovers the sample values at the nodes.");
+
+        let nodes = points.iter().map(|&(x, _)| x).collect::<Vec<f64>>();
+        for &(x, y) in points.iter() {
+            assert!(eval_newton(&coeffs, &nodes, x).approx_eq(&y, 1e-12));
+        }
+
+        println!("Asserting that the Newton form agrees with the polynomial away from the nodes.");
+
+        assert!(eval_newton(&coeffs, &nodes, 426.0).approx_eq(&(2.0 * 426.0 * 426.0 - 3.0 * 426.0 + 1.0), 1e-6));
+        assert!(eval_newton(&coeffs, &nodes, -0.5).approx_eq(&3.0, 1e-12));
+
+        println!("Checking the edge cases of a single point and no points.");
+
+        assert_eq!(divided_differences(&[(426.0, 2.5)]), vec![2.5]);
+        assert_eq!(eval_newton(&[2.5], &[426.0], 1.0), 2.5);
+        assert_eq!(eval_newton(&[], &[], 1.0), 0.0);
+    }
+
+    #[test]
+    fn multiply_by_linear_test() {
+        println!("Building (X - 1)(X - 2) = X^2 - 3X + 2 from the one polynomial.");
+
+        let mut coeffs = vec![1];
+        multiply_by_linear(&mut coeffs, 1, Modulus::None);
+        multiply_by_linear(&mut coeffs, 2, Modulus::None);
+        assert_eq!(coeffs, vec![2, -3, 1]);
+
+        println!("Asserting that the result agrees with multiply_poly.");
+
+        multiply_by_linear(&mut coeffs, -426, Modulus::None);
+        assert_eq!(
+            IntPoly::new(&mut coeffs, Modulus::None).unwrap(),
+            multiply_poly(
+                &IntPoly::new(&mut vec![2, -3, 1], Modulus::None).unwrap(),
+                &IntPoly::new(&mut vec![426, 1], Modulus::None).unwrap()
+            ).unwrap()
+        );
+
+        println!("Building (X - 1)(X - 2) over Z/5Z, where the coefficients are reduced.");
+
+        let mut coeffs_mod = vec![1];
+        multiply_by_linear(&mut coeffs_mod, 1, Modulus::Some(5));
+        multiply_by_linear(&mut coeffs_mod, 2, Modulus::Some(5));
+        assert_eq!(coeffs_mod, vec![2, 2, 1]);
+
+        println!("Building (X - 100000)^2 over Z/1000003Z, where products of the root and residues exceed i32::MAX.");
+
+        let mut coeffs_large = vec![1];
+        multiply_by_linear(&mut coeffs_large, 100000, Modulus::Some(1_000_003));
+        multiply_by_linear(&mut coeffs_large, 100000 - 1_000_003, Modulus::Some(1_000_003));
+        // X^2 - 200000X + 10^10
+        assert_eq!(coeffs_large, vec![970003, 800003, 1]);
+
+        println!("Asserting that the zero polynomial stays the zero polynomial.");
+
+        let mut zero = vec![];
+        multiply_by_linear(&mut zero, 426, Modulus::None);
+        assert_eq!(zero, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn map_coefficients_pow_test() {
+        println!("Squaring the coefficients of 2 + 3X over Z/5Z.");
+
+        let poly = IntPoly::new(&mut vec![2, 3], Modulus::Some(5)).unwrap();
+        assert_eq!(poly.map_coefficients_pow(2), Ok(IntPoly::new(&mut vec![4, 4], Modulus::Some(5)).unwrap()));
+
+        println!("Asserting that raising to the p-th power is the identity on the coefficients.");
+
+        let poly2 = IntPoly::new(&mut vec![426, -1, 0, 3], Modulus::Some(7)).unwrap();
+        assert_eq!(poly2.map_coefficients_pow(7), Ok(poly2.reduced()));
+
+        println!("Asserting that integer polynomials are rejected.");
+
+        assert_eq!(
+            IntPoly::new(&mut vec![2, 3], Modulus::None).unwrap().map_coefficients_pow(2),
+            Err(PolynomialError::NoModulusError)
+        );
+    }
+
+    #[test]
+    fn divide_poly_identity_test() {
+        let mut rng = StdRng::seed_from_u64(426);
+
+        println!("Asserting that dividend = quotient * divisor + remainder with deg(remainder) < deg(divisor) over Z/7Z.");
+
+        for (dividend_degree, divisor_degree) in [(5, 2), (3, 3), (1, 4), (8, 1)] {
+            let dividend = random_poly(dividend_degree, Modulus::Some(7), &mut rng).unwrap();
+            let divisor = random_poly(divisor_degree, Modulus::Some(7), &mut rng).unwrap();
+            let (quotient, remainder) = divide_poly(&dividend, &divisor).unwrap();
+
+            assert!(remainder.deg() < divisor.deg());
+            assert_eq!(
+                add_poly(&multiply_poly(&quotient, &divisor).unwrap(), &remainder).unwrap().reduced(),
+                dividend
+            );
+        }
+
+        println!("Asserting the same identity over Z/65537Z, where products of coefficients exceed i32::MAX.");
+
+        for (dividend_degree, divisor_degree) in [(5, 2), (3, 3), (8, 1)] {
+            let dividend = random_poly(dividend_degree, Modulus::Some(65537), &mut rng).unwrap();
+            let divisor = random_poly(divisor_degree, Modulus::Some(65537), &mut rng).unwrap();
+            let (quotient, remainder) = divide_poly(&dividend, &divisor).unwrap();
+
+            assert!(remainder.deg() < divisor.deg());
+            assert!(remainder.is_reduced());
+            assert_eq!(
+                add_poly(&multiply_poly_checked(&quotient, &divisor).unwrap(), &remainder).unwrap().reduced(),
+                dividend
+            );
+        }
+
+        println!("Asserting that polynomials with different moduli cannot be divided.");
+
+        assert_eq!(
+            divide_poly(&one_polynomial(Modulus::Some(7)).unwrap(), &one_polynomial(Modulus::None).unwrap()),
+            Err(PolynomialError::ModulusMismatchError(Modulus::Some(7), Modulus::None))
+        );
+    }
+
+    #[test]
+    fn modular_equality_test() {
+        println!("Asserting that 1 + 6X and 1 + X are equal over Z/5Z.");
+
+        assert_eq!(
+            IntPoly::new(&mut vec![1, 6], Modulus::Some(5)).unwrap(),
+            IntPoly::new(&mut vec![1, 1], Modulus::Some(5)).unwrap()
+        );
+        assert_eq!(
+            IntPoly::new(&mut vec![-4, 426, -1], Modulus::Some(5)).unwrap(),
+            IntPoly::new(&mut vec![1, 1, 4], Modulus::Some(5)).unwrap()
+        );
+        assert!(IntPoly::new(&mut vec![1, 6], Modulus::Some(5)).unwrap() != IntPoly::new(&mut vec![1, 2], Modulus::Some(5)).unwrap());
+
+        println!("Asserting that a product compares equal to its expected value without manual reduction.");
+
+        let product = multiply_poly(
+            &IntPoly::new(&mut vec![3, 4], Modulus::Some(5)).unwrap(),
+            &IntPoly::new(&mut vec![2, 1], Modulus::Some(5)).unwrap()
+        ).unwrap();
+        assert_eq!(product, IntPoly::new(&mut vec![1, 1, 4], Modulus::Some(5)).unwrap());
+
+        println!("Asserting that polynomials with different moduli are never equal.");
+
+        assert!(IntPoly::new(&mut vec![1, 1], Modulus::Some(5)).unwrap() != IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap());
+        assert!(IntPoly::new(&mut vec![1, 1], Modulus::Some(5)).unwrap() != IntPoly::new(&mut vec![1, 1], Modulus::Some(7)).unwrap());
+
+        println!("Asserting that integer polynomials compare their coefficients as they are.");
+
+        assert_eq!(IntPoly::new(&mut vec![1, 6], Modulus::None).unwrap(), IntPoly::new(&mut vec![1, 6, 0], Modulus::None).unwrap());
+        assert!(IntPoly::new(&mut vec![1, 6], Modulus::None).unwrap() != IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap());
+        assert!(IntPoly::new(&mut vec![-1], Modulus::None).unwrap() != IntPoly::new(&mut vec![1], Modulus::None).unwrap());
+    }
+
+    #[test]
+    fn poly_power_high_exponent_test() {
+        println!("Asserting that (1 + X + X^2)^1000 over Z/7Z matches repeated multiplication.");
+
+        let poly = IntPoly::new(&mut vec![1, 1, 1], Modulus::Some(7)).unwrap();
+        let mut expected = one_polynomial(Modulus::Some(7)).unwrap();
+        for _ in 0..1000 {
+            expected = multiply_poly(&poly, &expected).unwrap().reduced();
+        }
+
+        let power = poly_power(&poly, 1000).unwrap();
+        assert_eq!(power.deg(), 2000);
+        assert_eq!(power, expected);
+
+        println!("Asserting that (1 + X)^13 over the integers has binomial coefficients.");
+
+        let power2 = poly_power(&IntPoly::new(&mut vec![1, 1], Modulus::None).unwrap(), 13).unwrap();
+        let mut binomial = 1;
+        for k in 0..14 {
+            assert_eq!(power2.coefficient(k), binomial);
+            binomial = binomial * (13 - k as i32) / (k as i32 + 1);
+        }
+
+        println!("Asserting that exponents 0 and 1 are handled correctly.");
+
+        assert_eq!(poly_power(&poly, 0), Ok(one_polynomial(Modulus::Some(7)).unwrap()));
+        assert_eq!(poly_power(&poly, 1), Ok(poly));
+    }
+
+    #[test]
+    fn poly_gcd_shared_factor_test() {
+        let mut rng = StdRng::seed_from_u64(426);
+
+        println!("Asserting that the gcd of f * h and g * h over Z/11Z is monic and a common divisor divisible by h.");
+
+        for _ in 0..10 {
+            let f = random_poly(3, Modulus::Some(11), &mut rng).unwrap();
+            let g = random_poly(4, Modulus::Some(11), &mut rng).unwrap();
+            let h = random_poly(2, Modulus::Some(11), &mut rng).unwrap();
+            let fh = multiply_poly(&f, &h).unwrap();
+            let gh = multiply_poly(&g, &h).unwrap();
+
+            let gcd = poly_gcd(&fh, &gh).unwrap();
+            assert_eq!(gcd.leading_coefficient(), 1);
+            assert_eq!(h.divides(&gcd), Ok(true));
+            assert_eq!(gcd.divides(&fh), Ok(true));
+            assert_eq!(gcd.divides(&gh), Ok(true));
+        }
+
+        println!("Asserting the same over Z/65537Z, where products of coefficients exceed i32::MAX.");
+
+        for _ in 0..10 {
+            let f = random_poly(3, Modulus::Some(65537), &mut rng).unwrap();
+            let g = random_poly(4, Modulus::Some(65537), &mut rng).unwrap();
+            let h = random_poly(2, Modulus::Some(65537), &mut rng).unwrap();
+            let fh = multiply_poly_checked(&f, &h).unwrap();
+            let gh = multiply_poly_checked(&g, &h).unwrap();
+
+            let gcd = poly_gcd(&fh, &gh).unwrap();
+            assert_eq!(gcd.leading_coefficient(), 1);
+            assert_eq!(h.divides(&gcd), Ok(true));
+            assert_eq!(gcd.divides(&fh), Ok(true));
+            assert_eq!(gcd.divides(&gh), Ok(true));
+        }
+
+        println!("Asserting that 3 + 60000X and 5 + 60000X are coprime over Z/65537Z.");
+
+        let a = IntPoly::new(&mut vec![3, 60000], Modulus::Some(65537)).unwrap();
+        let b = IntPoly::new(&mut vec![5, 60000], Modulus::Some(65537)).unwrap();
+        assert_eq!(poly_gcd(&a, &b), Ok(one_polynomial(Modulus::Some(65537)).unwrap()));
+
+        println!("Asserting that the gcd with the zero polynomial is the monic associate.");
+
+        let poly = IntPoly::new(&mut vec![3, 2], Modulus::Some(11)).unwrap(); // 2X + 3 = 2 (X + 7)
+        assert_eq!(poly_gcd(&poly, &zero_polynomial(Modulus::Some(11)).unwrap()), Ok(IntPoly::new(&mut vec![7, 1], Modulus::Some(11)).unwrap()));
+        assert_eq!(poly_gcd(&zero_polynomial(Modulus::Some(11)).unwrap(), &poly), Ok(IntPoly::new(&mut vec![7, 1], Modulus::Some(11)).unwrap()));
+    }
+
+    #[test]
+    fn multiply_poly_karatsuba_test() {
+        let mut rng = StdRng::seed_from_u64(426);
+
+        println!("Comparing Karatsuba multiplication with the schoolbook method for many sizes.");
+
+        for md in [Modulus::None, Modulus::Some(7), Modulus::Some(1009)] {
+            for (deg1, deg2) in [(0, 0), (5, 40), (31, 31), (32, 32), (33, 100), (64, 64), (127, 40), (200, 199)] {
+                let poly1 = random_poly(deg1, md, &mut rng).unwrap();
+                let poly2 = random_poly(deg2, md, &mut rng).unwrap();
+
+                let product = multiply_poly_karatsuba(&poly1, &poly2).unwrap();
+                assert_eq!(product, multiply_poly(&poly1, &poly2).unwrap());
+                assert_eq!(product.deg(), (deg1 + deg2) as i32);
+                assert_eq!(product.modulus, md);
+            }
+        }
+
+        println!("Asserting that products with the zero polynomial keep the modulus.");
+
+        let poly = random_poly(50, Modulus::Some(7), &mut rng).unwrap();
+        assert_eq!(multiply_poly_karatsuba(&poly, &zero_polynomial(Modulus::Some(7)).unwrap()), Ok(zero_polynomial(Modulus::Some(7)).unwrap()));
+
+        println!("Asserting that mismatching moduli and overflows are reported.");
+
+        assert_eq!(
+            multiply_poly_karatsuba(&poly, &one_polynomial(Modulus::None).unwrap()),
+            Err(PolynomialError::ModulusMismatchError(Modulus::Some(7), Modulus::None))
+        );
+        let big = IntPoly::new(&mut vec![100_000; 40], Modulus::None).unwrap();
+        assert_eq!(multiply_poly_karatsuba(&big, &big), Err(PolynomialError::OverflowError));
+
+        println!("Asserting that products of extreme coefficients are reported as overflows instead of panicking.");
+
+        let min = IntPoly::new(&mut vec![i32::MIN, i32::MIN], Modulus::None).unwrap();
+        assert_eq!(multiply_poly_karatsuba(&min, &min), Err(PolynomialError::OverflowError));
+        let max = IntPoly::new(&mut vec![i32::MAX; 64], Modulus::None).unwrap();
+        assert_eq!(multiply_poly_karatsuba(&max, &max), Err(PolynomialError::OverflowError));
+        assert_eq!(multiply_poly_karatsuba(&max, &max), multiply_poly_checked(&max, &max));
+
+        println!("Comparing Karatsuba multiplication with checked multiplication for extreme coefficients that fit.");
+
+        let alternating = IntPoly::new(&mut (0..64).map(|i| if i % 2 == 0 { i32::MAX } else { -i32::MAX }).collect(), Modulus::None).unwrap();
+        let ones = IntPoly::new(&mut vec![1; 40], Modulus::None).unwrap();
+        assert_eq!(multiply_poly_karatsuba(&alternating, &ones), multiply_poly_checked(&alternating, &ones));
+        assert!(multiply_poly_karatsuba(&alternating, &ones).is_ok());
+    }
+
+    #[test]
+    fn invalid_modulus_test() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(426);
+
+        for q in [0, 1, -5] {
+            println!("Asserting that polynomials with modulus {} are rejected.", q);
+
+            assert_eq!(IntPoly::new(&mut vec![1, 2], Modulus::Some(q)), Err(PolynomialError::InvalidModulusError(q)));
+            assert_eq!(zero_polynomial(Modulus::Some(q)), Err(PolynomialError::InvalidModulusError(q)));
+            assert_eq!(one_polynomial(Modulus::Some(q)), Err(PolynomialError::InvalidModulusError(q)));
+            assert_eq!(random_poly(2, Modulus::Some(q), &mut rng), Err(PolynomialError::InvalidModulusError(q)));
+        }
+
+        println!("Asserting that the smallest valid modulus 2 is accepted.");
+        assert!(IntPoly::new(&mut vec![1, 2], Modulus::Some(2)).is_ok());
+    }
+
+    #[test]
+    fn operator_test() {
+        let p = IntPoly::new(&mut vec![1, 2], Modulus::Some(7)).unwrap();
+        let q = IntPoly::new(&mut vec![3, 0, 5], Modulus::Some(7)).unwrap();
+
+        println!("Asserting that the operators agree with the fallible functions.");
+
+        assert_eq!(&p + &q, add_poly(&p, &q).unwrap());
+        assert_eq!(&p - &q, subtract_poly(&p, &q).unwrap());
+        assert_eq!(&p * &q, multiply_poly(&p, &q).unwrap());
+        assert_eq!(-&p, p.additive_inverse());
+
+        println!("Asserting that (1 + 2X) * (3 + 5X^2) - (1 + 2X) + (1 + 2X) = 3 + 6X + 5X^2 + 3X^3 over Z/7Z.");
+
+        assert_eq!(&(&(&p * &q) - &p) + &p, IntPoly::new(&mut vec![3, 6, 5, 3], Modulus::Some(7)).unwrap());
+        assert_eq!(&p + &(-&p), zero_polynomial(Modulus::Some(7)).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "ModulusMismatchError")]
+    fn operator_modulus_mismatch_test() {
+        let p = IntPoly::new(&mut vec![1, 2], Modulus::Some(7)).unwrap();
+        let q = IntPoly::new(&mut vec![1, 2], Modulus::None).unwrap();
+
+        let _ = &p + &q;
     }
 }
\ No newline at end of file
@@ -0,0 +1,258 @@
+//! Exact arithmetic with rational numbers.
+
+
+
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use crate::number_theory::euclid; // for reducing fractions
+
+/// Models a rational number numerator / denominator.
+///
+/// Rational numbers are always stored in lowest terms with a positive denominator,
+/// so two instances are equal if and only if they model the same number.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Rational {
+    numerator: i32,
+    denominator: i32
+}
+
+impl Rational {
+    /// Constructor, creates the rational number numerator / denominator in lowest terms.
+    ///
+    /// If the denominator is 0, an error is returned.
+    pub fn new(numerator: i32, denominator: i32) -> Result<Rational, RationalError> {
+        if denominator == 0 {
+            return Err(RationalError::ZeroDenominatorError);
+        }
+
+        // cancel the gcd and move the sign to the numerator
+        let gcd = euclid(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+
+        Ok(
+            Rational {
+                numerator: sign * numerator / gcd,
+                denominator: sign * denominator / gcd
+            }
+        )
+    }
+
+    /*
+    * Creates the rational number numerator / denominator 
+    * from numbers that are already in lowest terms with positive denominator,
+    * as computed with 64 bits by the arithmetic operations.
+    * Panics if they do not fit into an i32, just like an integer overflow.
+    */
+    fn from_lowest_terms(numerator: i64, denominator: i64) -> Rational {
+        match (i32::try_from(numerator), i32::try_from(denominator)) {
+            (Ok(numerator), Ok(denominator)) => Rational { numerator, denominator },
+            _ => panic!(
+                "Attempted to create the rational number {}/{} that does not fit into i32.", 
+                numerator, denominator
+            )
+        }
+    }
+
+    /// Returns the rational number n / 1.
+    pub fn from_integer(n: i32) -> Rational {
+        Rational {
+            numerator: n,
+            denominator: 1
+        }
+    }
+
+    /// Returns the numerator of the number in lowest terms.
+    pub fn numerator(self: &Self) -> i32 {
+        self.numerator
+    }
+
+    /// Returns the (positive) denominator of the number in lowest terms.
+    pub fn denominator(self: &Self) -> i32 {
+        self.denominator
+    }
+
+    /// Determines whether the number is 0.
+    pub fn is_zero(self: &Self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Returns the closest floating point approximation of the number.
+    pub fn to_f32(self: &Self) -> f32 {
+        (self.numerator as f32) / (self.denominator as f32)
+    }
+}
+
+/// Sum of two rational numbers.
+/// Common factors of the denominators are cancelled first and intermediate results have 64 bits,
+/// so this only panics if the sum in lowest terms does not fit into an i32.
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        /*
+        * a/b + c/d = (a * d/g + c * b/g) / (b/g * d) with g = gcd(b, d).
+        * Since a/b and c/d are in lowest terms, 
+        * the new numerator t can only share prime factors of g with the denominator.
+        */
+        let g = euclid(self.denominator, other.denominator);
+        let t = (self.numerator as i64) * ((other.denominator / g) as i64)
+            + (other.numerator as i64) * ((self.denominator / g) as i64);
+        let h = euclid((t % g as i64) as i32, g) as i64;
+
+        Rational::from_lowest_terms(
+            t / h,
+            ((self.denominator / g) as i64) * ((other.denominator as i64) / h)
+        )
+    }
+}
+
+/// Difference of two rational numbers.
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + (-other)
+    }
+}
+
+/// Product of two rational numbers.
+/// Common factors are cancelled crosswise first and intermediate results have 64 bits,
+/// so this only panics if the product in lowest terms does not fit into an i32.
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        // after cancelling crosswise, numerator and denominator of the product are coprime
+        let g1 = euclid(self.numerator, other.denominator);
+        let g2 = euclid(other.numerator, self.denominator);
+
+        Rational::from_lowest_terms(
+            ((self.numerator / g1) as i64) * ((other.numerator / g2) as i64),
+            ((self.denominator / g2) as i64) * ((other.denominator / g1) as i64)
+        )
+    }
+}
+
+/// Quotient of two rational numbers.
+/// Panics when dividing by 0, just like integer division,
+/// or if the quotient in lowest terms does not fit into an i32.
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        if other.is_zero() {
+            panic!("Attempted to divide a rational number by zero.");
+        }
+
+        // dividing by c/d is multiplying with d/c, the sign is moved to the numerator
+        let reciprocal = Rational::from_lowest_terms(
+            (other.numerator.signum() as i64) * (other.denominator as i64),
+            (other.numerator as i64).abs()
+        );
+
+        self * reciprocal
+    }
+}
+
+/// Additive inverse of a rational number.
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator
+        }
+    }
+}
+
+/// Type modelling all different kinds of errors
+/// that can occur when working with rational numbers.
+#[derive(PartialEq, Debug, Clone)]
+pub enum RationalError {
+    /// Returned when attempting to construct a fraction with denominator 0.
+    ZeroDenominatorError
+}
+
+
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::rational::*;
+
+    #[test]
+    fn rational_construction_test() {
+        println!("Asserting that fractions are reduced to lowest terms with positive denominator.");
+
+        assert_eq!(Rational::new(426, 18), Rational::new(71, 3));
+        assert_eq!(Rational::new(2, -4), Rational::new(-1, 2));
+        assert_eq!(Rational::new(0, -426), Ok(Rational::from_integer(0)));
+
+        let x = Rational::new(-6, -4).unwrap();
+        assert_eq!(x.numerator(), 3);
+        assert_eq!(x.denominator(), 2);
+
+        println!("Asserting that a zero denominator is rejected.");
+
+        assert_eq!(Rational::new(426, 0), Err(RationalError::ZeroDenominatorError));
+    }
+
+    #[test]
+    fn rational_arithmetic_test() {
+        let half = Rational::new(1, 2).unwrap();
+        let third = Rational::new(1, 3).unwrap();
+
+        println!("Testing sums, differences, products and quotients of 1/2 and 1/3.");
+
+        assert_eq!(half + third, Rational::new(5, 6).unwrap());
+        assert_eq!(half - third, Rational::new(1, 6).unwrap());
+        assert_eq!(half * third, Rational::new(1, 6).unwrap());
+        assert_eq!(half / third, Rational::new(3, 2).unwrap());
+        assert_eq!(-half, Rational::new(-1, 2).unwrap());
+
+        println!("Asserting that results are exact, e.g. 3 * (1/3) = 1.");
+
+        assert_eq!(Rational::from_integer(3) * third, Rational::from_integer(1));
+        assert_eq!(half.to_f32(), 0.5);
+    }
+
+    #[test]
+    fn rational_cancellation_test() {
+        println!("Asserting that common factors are cancelled before multiplying, so no overflow occurs.");
+
+        let x = Rational::new(1, 46341).unwrap();
+
+        assert_eq!(x + x, Rational::new(2, 46341).unwrap());
+        assert_eq!(x - x, Rational::from_integer(0));
+        assert_eq!(x * Rational::from_integer(46341), Rational::from_integer(1));
+        assert_eq!(x / x, Rational::from_integer(1));
+        assert_eq!(
+            Rational::new(1, 46340).unwrap() - x, 
+            Rational::new(1, 46340 * 46341).unwrap()
+        );
+        assert_eq!(
+            Rational::new(65536, 65537).unwrap() * Rational::new(65537, 131072).unwrap(),
+            Rational::new(1, 2).unwrap()
+        );
+        assert_eq!(
+            Rational::new(-3, 65536).unwrap() / Rational::new(-9, 65536).unwrap(),
+            Rational::new(1, 3).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rational_overflow_test() {
+        let _ = Rational::from_integer(i32::MAX) + Rational::from_integer(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rational_division_by_zero_test() {
+        let _ = Rational::from_integer(426) / Rational::from_integer(0);
+    }
+}
\ No newline at end of file
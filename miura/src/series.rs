@@ -1,8 +1,148 @@
+//! Computations with (truncated) power series.
+
+
+
+use std::cmp::min;
+use std::ops::{Add, Mul};
+
 /// How many summands of a power series are computed.
 /// Small number means higher efficiency 
 /// while many iterations yield higher precision.
 pub const NUM_ITERATIONS: usize = 100;
 
+/// Models a power series a_0 + a_1 * X + a_2 * X^2 + ... 
+/// that is truncated after the monomial of degree order.
+///
+/// The series is stored as a vector of order + 1 coefficients,
+/// coeffs[k] is the coefficient for X^k.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PowerSeries {
+    coeffs: Vec<f64>,
+    order: usize
+}
+
+impl PowerSeries {
+    /// Constructs a power series truncated after the monomial of degree order
+    /// from the passed coefficient vector.
+    ///
+    /// Coefficients for monomials of degree higher than order are cut,
+    /// missing coefficients up to degree order are 0.
+    pub fn new(coeffs: Vec<f64>, order: usize) -> PowerSeries {
+        let mut truncated_coeffs = coeffs;
+        truncated_coeffs.resize(order + 1, 0.0);
+
+        PowerSeries {
+            coeffs: truncated_coeffs,
+            order
+        }
+    }
+
+    /// Returns the power series of the exponential function,
+    /// i.e. the sum of X^k / k! for k in {0, ..., order}.
+    pub fn exp(order: usize) -> PowerSeries {
+        let mut coeffs = vec![1.0];
+
+        // k! is computed iteratively from (k-1)!
+        let mut k_factorial = 1.0;
+        for k in 1..(order+1) {
+            k_factorial *= k as f64;
+            coeffs.push(1.0 / k_factorial);
+        }
+
+        PowerSeries::new(coeffs, order)
+    }
+
+    /// Returns the power series of the sine function,
+    /// i.e. the sum of (-1)^k * X^(2k+1) / (2k+1)! for 2k+1 <= order.
+    pub fn sin(order: usize) -> PowerSeries {
+        /*
+        * The coefficients of the sine series are the ones of the exponential series
+        * with the even ones removed and the signs of the odd ones alternating.
+        */
+        let coeffs = PowerSeries::exp(order).coeffs.iter().enumerate()
+            .map(|(k, &a_k)| match k % 4 {
+                1 => a_k,
+                3 => -a_k,
+                _ => 0.0
+            })
+            .collect::<Vec<f64>>();
+
+        PowerSeries::new(coeffs, order)
+    }
+
+    /// Returns the power series of the cosine function,
+    /// i.e. the sum of (-1)^k * X^(2k) / (2k)! for 2k <= order.
+    pub fn cos(order: usize) -> PowerSeries {
+        /*
+        * The coefficients of the cosine series are the ones of the exponential series
+        * with the odd ones removed and the signs of the even ones alternating.
+        */
+        let coeffs = PowerSeries::exp(order).coeffs.iter().enumerate()
+            .map(|(k, &a_k)| match k % 4 {
+                0 => a_k,
+                2 => -a_k,
+                _ => 0.0
+            })
+            .collect::<Vec<f64>>();
+
+        PowerSeries::new(coeffs, order)
+    }
+
+    /// Returns the degree of the highest monomial that is kept in this truncated series.
+    pub fn order(self: &Self) -> usize {
+        self.order
+    }
+
+    /// Returns the coefficient for the monomial with the passed exponent.
+    /// Coefficients beyond the order of the series are 0.
+    pub fn coefficient(self: &Self, exponent: usize) -> f64 {
+        if exponent > self.order { 0.0 } else { self.coeffs[exponent] }
+    }
+
+    /// Evaluates the truncated power series at the passed point x
+    /// using Horner's scheme.
+    pub fn eval(self: &Self, x: f64) -> f64 {
+        self.coeffs.iter().rev().fold(0.0, |acc, &a_k| acc * x + a_k)
+    }
+}
+
+/// Sum of two power series.
+/// The result is truncated to the smaller of the two orders,
+/// since the coefficients beyond it are not known for both summands.
+impl Add for PowerSeries {
+    type Output = PowerSeries;
+
+    fn add(self, other: PowerSeries) -> PowerSeries {
+        let order = min(self.order, other.order);
+
+        PowerSeries::new(
+            (0..(order+1)).map(|k| self.coeffs[k] + other.coeffs[k]).collect(),
+            order
+        )
+    }
+}
+
+/// Product (Cauchy product) of two power series.
+/// The result is truncated to the smaller of the two orders,
+/// since the coefficients beyond it are not known for both factors.
+impl Mul for PowerSeries {
+    type Output = PowerSeries;
+
+    fn mul(self, other: PowerSeries) -> PowerSeries {
+        let order = min(self.order, other.order);
+
+        /*
+        * The coefficient for X^k in the product is the convolution
+        * a_0 * b_k + a_1 * b_(k-1) + ... + a_k * b_0.
+        */
+        let coeffs = (0..=order)
+            .map(|k| self.coeffs[..=k].iter().zip(other.coeffs[..=k].iter().rev()).map(|(a_i, b_j)| a_i * b_j).sum())
+            .collect::<Vec<f64>>();
+
+        PowerSeries::new(coeffs, order)
+    }
+}
+
 /// Computes the exponential function via power series expansion.
 /// WIP function, currently lacking suitable floating point precision.
 pub fn exp(x: f64) -> f64 {
@@ -18,7 +158,7 @@ pub fn exp(x: f64) -> f64 {
 
     for k in 1..NUM_ITERATIONS {
         x_pow_k *= x;
-        k_factorial = k_factorial * (k as f64);
+        k_factorial *= k as f64;
 
         result += x_pow_k / k_factorial;
     }
@@ -36,14 +176,14 @@ pub fn exp(x: f64) -> f64 {
 #[cfg(test)]
 mod tests {
     use crate::series::*;
+    use crate::approx::ApproxEq;
 
     #[test]
     fn exp_test() {
         println!("Evaluating the exponential function for some positive integer.");
 
         // precision of 14
-        assert!(exp(3.0) >= 20.08553692318766);
-        assert!(exp(3.0) < 20.08553692318767);
+        assert!(exp(3.0).approx_eq(&20.085536923187668, 1e-14));
 
         println!("Evaluating the exponential function for 0.");
 
@@ -53,4 +193,36 @@ mod tests {
 
         println!("Can't be tested yet due to lack of floating point precision!");
     }
+
+    #[test]
+    fn power_series_product_test() {
+        println!("Multiplying the series for e^x and e^(-x), expecting the constant series 1.");
+
+        let exp_series = PowerSeries::exp(20);
+
+        // e^(-x) has the coefficients (-1)^k / k!
+        let exp_neg_series = PowerSeries::new(
+            (0..21).map(|k| if k % 2 == 0 { exp_series.coefficient(k) } else { -exp_series.coefficient(k) }).collect(),
+            20
+        );
+
+        let product = exp_series * exp_neg_series;
+
+        assert_eq!(product.order(), 20);
+        assert!((product.coefficient(0) - 1.0).abs() < 1e-12);
+        for k in 1..21 {
+            assert!(product.coefficient(k).abs() < 1e-12);
+        }
+
+        println!("Asserting that sin^2 + cos^2 evaluates to 1.");
+
+        let sin_squared = PowerSeries::sin(30) * PowerSeries::sin(30);
+        let cos_squared = PowerSeries::cos(30) * PowerSeries::cos(30);
+
+        assert!(((sin_squared + cos_squared).eval(0.5) - 1.0).abs() < 1e-12);
+
+        println!("Evaluating the sine series.");
+
+        assert!((PowerSeries::sin(30).eval(1.0) - 1.0_f64.sin()).abs() < 1e-12);
+    }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@
 
 
 use std::collections::HashMap; // for counting occurences in counting sort
+use std::thread; // for parallel merge sort
 
 /// Incremental sorting algorithm.
 ///
@@ -54,6 +55,41 @@ pub fn insertion_sort(a: &mut Vec<u32>) {
     }
 }
 
+/// Incremental sorting algorithm like insertion_sort for arbitrary comparable elements in a slice,
+/// which additionally counts the performed operations.
+///
+/// Every element is moved to the left by swapping it with its left neighbour 
+/// as long as this neighbour is greater.
+/// Returns the number of comparisons of two elements and the number of these swaps (moves),
+/// e.g. a reverse-sorted input of length n needs n(n-1)/2 of both.
+pub fn insertion_sort_instrumented<T: Ord>(a: &mut [T]) -> SortStats {
+    let mut stats = SortStats {
+        comparisons: 0,
+        moves: 0
+    };
+
+    /*
+    * Insert the j-th element within the first j ones in the slice.
+    * Invariant: after the iteration of this loop for some j, 
+    * the first j elements in the slice are sorted.
+    */
+    for j in 1..a.len() {
+        let mut i = j;
+        while i > 0 {
+            stats.comparisons += 1;
+            if a[i-1] <= a[i] {
+                break;
+            }
+
+            a.swap(i-1, i);
+            stats.moves += 1;
+            i -= 1;
+        }
+    }
+
+    stats
+}
+
 /// Uses the merge sort algorithm to sort the passed vector of non-negative integers.
 ///
 /// Merge sort is a classic divide-and-conquer algorithm, 
@@ -79,36 +115,110 @@ pub fn merge_sort(a: &Vec<u32>) -> Vec<u32> {
     let right = &a[m..];
     
     // recursively sort the two subvectors and merge the result
-    merge(&merge_sort( &(left.to_vec()) ), &merge_sort( &(right.to_vec()) ))
+    merge_sorted(&merge_sort( &(left.to_vec()) ), &merge_sort( &(right.to_vec()) ))
 }
 
-/// Merges the two sorted input vectors into one vector.
-/// Does not check whether the input arrays are actually sorted
-/// and thus is not exposed as a part of the public API.  
+/// Counts the inversions of the passed vector, 
+/// i.e. the pairs of positions (i, j) with i < j but a[i] > a[j],
+/// in O(n log n) time using the merge sort algorithm.
+///
+/// While merging two sorted halves, every element taken from the right half 
+/// forms an inversion with each element that is still left in the left half,
+/// so the inversions between the two halves can be counted during the merge step.
+/// The inversions within the halves are counted recursively.
+pub fn count_inversions(a: &[u32]) -> usize {
+    merge_sort_counting_inversions(a).1
+}
+
+/// Sorts the passed vector like merge_sort does
+/// and additionally returns the number of inversions of the vector.
+fn merge_sort_counting_inversions<T: Ord + Clone>(a: &[T]) -> (Vec<T>, usize) {
+    // array of length 0 or 1 is trivially sorted and does not contain inversions
+    if a.len() <= 1 {
+        return (a.to_vec(), 0);
+    }
+
+    // split input vector into two vectors just like merge_sort does
+    let m = a.len() / 2;
+    let (left, left_inversions) = merge_sort_counting_inversions(&a[..m]);
+    let (right, right_inversions) = merge_sort_counting_inversions(&a[m..]);
+
+    let (result, split_inversions) = merge_counting_inversions(&left, &right);
+
+    (result, left_inversions + right_inversions + split_inversions)
+}
+
+/// Computes the permutation of indices that sorts the passed slice (stably), 
+/// i.e. the indices of the elements of a in the order in which the elements appear in the sorted vector.
+///
+/// Uses merge sort on the pairs (element, index), so equal elements keep their relative order.
+pub fn argsort<T: Ord>(a: &[T]) -> Vec<usize> {
+    let pairs = a.iter().enumerate().map(|(i, x)| (x, i)).collect::<Vec<(&T, usize)>>();
+
+    merge_sort_counting_inversions(&pairs).0.iter().map(|&(_, i)| i).collect()
+}
+
+/// Sorts the two passed parallel slices by the keys, keeping the pairing of keys and values intact,
+/// i.e. the value at some position ends up at the same position as its key.
+/// The sorting is stable, so values with equal keys keep their relative order.
+///
+/// If the slices have different lengths, an Err variant is returned.
+pub fn sort_together<K: Ord + Clone, V: Clone>(keys: &[K], values: &[V]) -> Result<(Vec<K>, Vec<V>), SortingInstanceError> {
+    if keys.len() != values.len() {
+        return Err(SortingInstanceError);
+    }
+
+    let order = argsort(keys);
+
+    Ok((
+        order.iter().map(|&i| keys[i].clone()).collect(),
+        order.iter().map(|&i| values[i].clone()).collect()
+    ))
+}
+
+/// Merges the two sorted input slices into one sorted vector,
+/// e.g. as a building block for external sorting or k-way merging.
+///
+/// Assumes that both inputs are sorted ascendingly, which is not checked.
+/// For unsorted inputs, the result is not sorted either.
 /// 
-/// Iterates through the left and right vector (with two independent cursors)
+/// Iterates through the left and right slice (with two independent cursors)
 /// and always inserts the smaller of the current two elements into the result vector.
-fn merge(left: &Vec<u32>, right: &Vec<u32>) -> Vec<u32> {
+/// On ties, the element from the left slice is inserted first.
+pub fn merge_sorted<T: Ord + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    merge_counting_inversions(left, right).0
+}
+
+/// Merges the two sorted input vectors into one vector
+/// and additionally counts the inversions between them, 
+/// i.e. the pairs of an element in left and a smaller element in right.
+fn merge_counting_inversions<T: Ord + Clone>(left: &[T], right: &[T]) -> (Vec<T>, usize) {
     // define the two independent cursors
     let mut left_current = 0;
     let mut right_current = 0;
 
     // define empty result vector
-    let mut result = Vec::<u32>::new();
+    let mut result = Vec::<T>::with_capacity(left.len() + right.len());
+
+    // number of inversions between left and right found so far
+    let mut inversions = 0;
 
     // while both vectors have some uninserted elements left
-    while left_current <= left.len() - 1 && right_current <= right.len() - 1 {
+    while left_current < left.len() && right_current < right.len() {
         /*
         * Compare the two current elements,
         * insert smaller one
         * and move cursor in respective array.
         */
         if left[left_current] <= right[right_current] {
-            result.push(left[left_current]);
+            result.push(left[left_current].clone());
             left_current += 1;
         } else {
-            result.push(right[right_current]);
+            result.push(right[right_current].clone());
             right_current += 1;
+
+            // the current right element is smaller than all remaining left elements
+            inversions += left.len() - left_current;
         }
     }
 
@@ -118,13 +228,50 @@ fn merge(left: &Vec<u32>, right: &Vec<u32>) -> Vec<u32> {
     * Need to insert the remaining elements of the other input vector as well.
     */
     for i in left_current..left.len() {
-        result.push(left[i]);
+        result.push(left[i].clone());
     }
     for j in right_current..right.len() {
-        result.push(right[j]);
+        result.push(right[j].clone());
     }
 
-    result
+    (result, inversions)
+}
+
+/// Inputs shorter than this are sorted sequentially by parallel_merge_sort,
+/// since then the overhead of spawning threads outweighs the gain from parallelism.
+pub const PARALLEL_MERGE_SORT_THRESHOLD: usize = 10000;
+
+/// Uses the merge sort algorithm to sort the passed slice of arbitrary comparable elements,
+/// distributing the work among the passed number of threads.
+///
+/// The input is split into (at most) threads chunks of roughly equal size,
+/// which are sorted on separate threads (using merge sort) 
+/// and then merged into the result one after another.
+/// If only one thread is requested or the input is shorter than PARALLEL_MERGE_SORT_THRESHOLD,
+/// the input is sorted sequentially on the calling thread.
+pub fn parallel_merge_sort<T: Ord + Send + Clone>(a: &[T], threads: usize) -> Vec<T> {
+    if threads <= 1 || a.len() < PARALLEL_MERGE_SORT_THRESHOLD {
+        return merge_sort_counting_inversions(a).0;
+    }
+
+    // round up, so there are at most threads chunks
+    let chunk_size = a.len().div_ceil(threads);
+
+    /*
+    * Sort the chunks on separate threads, the scope joins all of them before returning.
+    * Every thread gets its own copy of its chunk, so the elements only need to be sendable.
+    */
+    let sorted_chunks = thread::scope(|scope| {
+        let handles = a.chunks(chunk_size)
+            .map(|chunk| chunk.to_vec())
+            .map(|chunk| scope.spawn(move || merge_sort_counting_inversions(&chunk).0))
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<Vec<T>>>()
+    });
+
+    // merge the sorted chunks
+    sorted_chunks.iter().fold(vec![], |result, chunk| merge_sorted(&result, chunk))
 }
 
 /// Uses the quicksort algorithm to sort the passed array of positive integers.
@@ -182,9 +329,19 @@ pub fn quicksort(a: &Vec<u32>) -> Vec<u32> {
 /// If there is a number above s in the vector a (invalid instance), counting sort returns an Err variant.
 ///
 /// Counting sort counts how often each number from {0, ..., s} occurs in the vector.
-/// It then creates a new vector into which it inserts all numbers as many times as they occured,
-/// starting with the lowest.
+/// The prefix sums of these counts are the positions where the occurences of the numbers start
+/// in the sorted vector, so every number can directly be placed at its position.
+/// This is the special case of counting_sort_by_key where every number is its own key.
 pub fn counting_sort(a: &Vec<u32>, s: u32) -> Result<Vec<u32>, SortingInstanceError> {
+    counting_sort_by_key(a, s, |&x| x)
+}
+
+/// Counts how often each number from {0, ..., s} occurs in the passed slice,
+/// just like counting sort does.
+///
+/// Returns (value, count) pairs for the values that occur at least once, in ascending order of the values.
+/// If there is a number above s in a (invalid instance), an Err variant is returned.
+pub fn value_counts(a: &[u32], s: u32) -> Result<Vec<(u32, u32)>, SortingInstanceError> {
     // check instance for validity: all values in a smaller/equal s?
     for &x in a.iter() { 
         if x > s { return Err(SortingInstanceError); }  
@@ -193,23 +350,170 @@ pub fn counting_sort(a: &Vec<u32>, s: u32) -> Result<Vec<u32>, SortingInstanceEr
     // create hash map number -> number of occurences (for numbers in {0, 1, ..., s})
     let mut counts = HashMap::<u32, u32>::new();
 
-    // create empty result vector
-    let mut result = vec![];
-
     // fill counts map
     for &x in a.iter() {
         let count = counts.entry(x).or_insert(0);
         *count += 1;
     }
 
-    // fill result vector based on counts
-    for i in 0..(s+1) { // range from 0 (inclusive) to s+1 (exclusive)
-        for j in 0..(*(counts.entry(i).or_insert(0))) {
-            result.push(i); // add j copies of i to the result vector where j is number of times i occurs in a
+    // collect the occuring numbers in ascending order (without iterating over all of {0, ..., s})
+    let mut result = counts.into_iter().collect::<Vec<(u32, u32)>>();
+    result.sort();
+
+    Ok(result)
+}
+
+/// Uses the counting sort algorithm to sort the passed slice of arbitrary elements 
+/// by a derived key from {0, ..., max_key}.
+///
+/// If the key of some element is above max_key (invalid instance), an Err variant is returned.
+///
+/// Counts how often each key occurs, which determines at which position 
+/// the elements with a certain key start in the sorted vector.
+/// Then the elements are placed at these positions in the order they occur in a,
+/// so the sorting is stable, i.e. elements with equal keys keep their relative order from the input.
+///
+/// If max_key is much larger than the length of a, only the occuring keys are counted (in a hash map)
+/// instead of allocating a count for every key in {0, ..., max_key}.
+pub fn counting_sort_by_key<T: Clone, F: Fn(&T) -> u32>(a: &[T], max_key: u32, key: F) -> Result<Vec<T>, SortingInstanceError> {
+    // check instance for validity: all keys smaller/equal max_key?
+    for x in a.iter() {
+        if key(x) > max_key { return Err(SortingInstanceError); }
+    }
+
+    /*
+    * Map every key to its index in the count vector: for a small key range, this is the key itself.
+    * For a large key range, the occuring keys are numbered in ascending order,
+    * so the counts (and prefix sums) only need one entry per distinct key.
+    */
+    let key_index: Option<HashMap<u32, usize>> = if (max_key as usize) <= SPARSE_COUNTING_FACTOR * a.len() {
+        None
+    } else {
+        let mut distinct_keys = a.iter().map(&key).collect::<Vec<u32>>();
+        distinct_keys.sort();
+        distinct_keys.dedup();
+        Some(distinct_keys.into_iter().enumerate().map(|(i, k)| (k, i)).collect())
+    };
+    let index = |x: &T| match &key_index {
+        None => key(x) as usize,
+        Some(key_index) => key_index[&key(x)]
+    };
+    let num_counts = match &key_index {
+        None => (max_key as usize) + 1,
+        Some(key_index) => key_index.len()
+    };
+
+    // count occurences of every key
+    let mut counts = vec![0; num_counts];
+    for x in a.iter() {
+        counts[index(x)] += 1;
+    }
+
+    // the elements with key k start behind all elements with smaller keys
+    let mut next_position = prefix_sums(&counts);
+
+    // place every element at the next free position for its key
+    let mut result: Vec<Option<T>> = vec![None; a.len()];
+    for x in a.iter() {
+        let k = index(x);
+        result[next_position[k] as usize] = Some(x.clone());
+        next_position[k] += 1;
+    }
+
+    // every position was filled exactly once since the counts sum up to the length of a
+    Ok(result.into_iter().map(|x| x.unwrap()).collect())
+}
+
+/* 
+* counting_sort_by_key only counts the occuring keys 
+* if the key range is larger than this factor times the number of elements.
+*/
+const SPARSE_COUNTING_FACTOR: usize = 8;
+
+/// Computes the exclusive prefix sums of the passed counts,
+/// i.e. the k-th entry of the result is counts[0] + ... + counts[k-1] (so the first entry is 0).
+///
+/// For the counts of the keys in counting sort, 
+/// these are the positions where the elements with the respective key start in the sorted vector.
+pub fn prefix_sums(counts: &[u32]) -> Vec<u32> {
+    let mut result = Vec::<u32>::with_capacity(counts.len());
+
+    let mut sum = 0;
+    for &count in counts.iter() {
+        result.push(sum);
+        sum += count;
+    }
+
+    result
+}
+
+/// Uses the bucket sort algorithm to sort the passed slice of arbitrary elements
+/// by a derived key from {0, ..., num_buckets - 1}.
+///
+/// Bucket sort distributes the elements into num_buckets buckets,
+/// where bucket k receives all elements with key k (in the order they occur in a).
+/// The buckets are then concatenated, starting with the one for the lowest key.
+/// Since all elements in a bucket share the same key, 
+/// the buckets need no further sorting and the algorithm is stable,
+/// i.e. elements with equal keys keep their relative order from the input.
+///
+/// Panics if the key of some element is not in {0, ..., num_buckets - 1}.
+pub fn bucket_sort<T: Clone, F: Fn(&T) -> usize>(a: &[T], num_buckets: usize, key: F) -> Vec<T> {
+    let mut buckets: Vec<Vec<T>> = vec![vec![]; num_buckets];
+
+    // distribute elements into buckets
+    for x in a.iter() {
+        let k = key(x);
+        if k >= num_buckets {
+            panic!("bucket_sort: key {} is out of range 0..{}", k, num_buckets);
         }
+        buckets[k].push(x.clone());
     }
 
-    Ok(result)
+    // concatenate buckets
+    buckets.concat()
+}
+
+/// Searches the passed target in the passed slice, which is assumed to be sorted in ascending order,
+/// using binary search with O(log n) comparisons.
+///
+/// If the target is contained, Ok with the index of its first occurrence is returned.
+/// Otherwise, Err with the insertion point is returned, 
+/// i.e. the index at which the target could be inserted such that the slice stays sorted.
+/// This matches the convention of the binary_search method of slices in the standard library.
+/// If the slice is not sorted, the result is meaningless (but the function still terminates).
+pub fn binary_search<T: Ord>(sorted: &[T], target: &T) -> Result<usize, usize> {
+    /*
+    * Invariant: all elements before low are smaller than the target
+    * and all elements from high on are at least as large as the target.
+    */
+    let mut low = 0;
+    let mut high = sorted.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if sorted[mid] < *target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    // low is the first index whose element is at least as large as the target
+    if low < sorted.len() && sorted[low] == *target {
+        Ok(low)
+    } else {
+        Err(low)
+    }
+}
+
+/// Counts of the operations performed by an instrumented sorting algorithm.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SortStats {
+    /// Number of comparisons of two elements.
+    pub comparisons: usize,
+    /// Number of moves (swaps) of elements.
+    pub moves: usize
 }
 
 /// Unit-like struct modelling any error that could occur from an unsuitable input to a sorting algorithm.
@@ -227,6 +531,7 @@ pub struct SortingInstanceError;
 #[cfg(test)]
 mod tests {
     use crate::sorting::*;
+    use rand::{Rng, SeedableRng, rngs::StdRng};
 
     #[test]
     fn sorting_test() {
@@ -274,4 +579,215 @@ mod tests {
         let vector2_mergesorted = merge_sort(&vector2);
         assert_eq!(vector2_mergesorted, vector2_sorted);
     }
+
+    #[test]
+    fn bucket_sort_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Task {
+            name: &'static str,
+            priority: usize
+        }
+
+        println!("Sorting tasks by priority with bucket sort.");
+
+        let tasks = vec![
+            Task { name: "a", priority: 2 },
+            Task { name: "b", priority: 0 },
+            Task { name: "c", priority: 3 },
+            Task { name: "d", priority: 2 },
+            Task { name: "e", priority: 0 }
+        ];
+
+        let sorted_tasks = bucket_sort(&tasks, 4, |t| t.priority);
+
+        assert_eq!(
+            sorted_tasks.iter().map(|t| t.priority).collect::<Vec<usize>>(),
+            vec![0, 0, 2, 2, 3]
+        );
+
+        println!("Asserting that tasks with equal priority keep their order.");
+
+        assert_eq!(
+            sorted_tasks.iter().map(|t| t.name).collect::<Vec<&str>>(),
+            vec!["b", "e", "a", "d", "c"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn bucket_sort_key_out_of_range_test() {
+        println!("Asserting that bucket sort panics on a key out of range.");
+
+        bucket_sort(&[1, 426, 2], 3, |&x| x);
+    }
+
+    #[test]
+    fn counting_sort_by_key_test() {
+        println!("Sorting (name, age) tuples by age with counting sort.");
+
+        let people = vec![("Alice", 32), ("Bob", 25), ("Carol", 32), ("Dave", 18), ("Eve", 25)];
+
+        assert_eq!(
+            counting_sort_by_key(&people, 100, |p| p.1),
+            Ok(vec![("Dave", 18), ("Bob", 25), ("Eve", 25), ("Alice", 32), ("Carol", 32)])
+        );
+
+        println!("Asserting that a key above the passed maximum key is rejected.");
+
+        assert_eq!(
+            counting_sort_by_key(&people, 30, |p| p.1),
+            Err(SortingInstanceError)
+        );
+
+        println!("Sorting with a key range much larger than the input without allocating a count for every key.");
+
+        assert_eq!(
+            counting_sort_by_key(&people, u32::MAX, |p| p.1 * 100_000_000),
+            Ok(vec![("Dave", 18), ("Bob", 25), ("Eve", 25), ("Alice", 32), ("Carol", 32)])
+        );
+        assert_eq!(counting_sort(&vec![5], 4_000_000_000), Ok(vec![5]));
+        assert_eq!(counting_sort(&vec![], u32::MAX), Ok(vec![]));
+        assert_eq!(counting_sort(&vec![u32::MAX, 0, 426, 0], u32::MAX), Ok(vec![0, 0, 426, u32::MAX]));
+    }
+
+    #[test]
+    fn count_inversions_test() {
+        println!("Counting the inversions of some vectors.");
+
+        assert_eq!(count_inversions(&[]), 0);
+        assert_eq!(count_inversions(&[426]), 0);
+        assert_eq!(count_inversions(&[1, 2, 3, 4]), 0);
+        assert_eq!(count_inversions(&[4, 3, 2, 1]), 6);
+        assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+        assert_eq!(count_inversions(&[3, 1, 3, 2]), 3);
+    }
+
+    #[test]
+    fn parallel_merge_sort_test() {
+        // seeded random number generator, so the test is deterministic
+        let mut rng = StdRng::seed_from_u64(426);
+        let a = (0..100000).map(|_| rng.gen_range(0..1 << 24)).collect::<Vec<u32>>();
+
+        println!("Asserting that parallel merge sort agrees with merge sort on a large random vector.");
+        let sequential = merge_sort(&a);
+        assert_eq!(parallel_merge_sort(&a, 4), sequential);
+        assert_eq!(parallel_merge_sort(&a, 7), sequential);
+
+        println!("Asserting that parallel merge sort with one thread agrees with merge sort.");
+        assert_eq!(parallel_merge_sort(&a, 1), sequential);
+
+        println!("Sorting short inputs and inputs of other types.");
+        assert_eq!(parallel_merge_sort(&Vec::<u32>::new(), 4), vec![]);
+        assert_eq!(parallel_merge_sort(&["c", "a", "b"], 4), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn value_counts_test() {
+        println!("Counting the occurences of values in a vector.");
+
+        assert_eq!(value_counts(&[3, 3, 1, 2, 2, 2], 3), Ok(vec![(1, 1), (2, 3), (3, 2)]));
+        assert_eq!(value_counts(&[], 426), Ok(vec![]));
+        assert_eq!(value_counts(&[u32::MAX, 0, u32::MAX], u32::MAX), Ok(vec![(0, 1), (u32::MAX, 2)]));
+
+        println!("Asserting that a value above the upper bound is rejected.");
+
+        assert_eq!(value_counts(&[3, 3, 1, 2, 2, 2], 2), Err(SortingInstanceError));
+    }
+
+    #[test]
+    fn merge_sorted_test() {
+        println!("Merging two sorted slices of integers.");
+
+        assert_eq!(merge_sorted(&[-3, 1, 4, 426], &[-5, 1, 2]), vec![-5, -3, 1, 1, 2, 4, 426]);
+
+        println!("Merging with empty slices.");
+
+        assert_eq!(merge_sorted(&[1, 2, 3], &[]), vec![1, 2, 3]);
+        assert_eq!(merge_sorted(&[], &[1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(merge_sorted::<i32>(&[], &[]), vec![]);
+    }
+
+    #[test]
+    fn prefix_sums_test() {
+        println!("Computing exclusive prefix sums.");
+
+        assert_eq!(prefix_sums(&[2, 0, 3, 1]), vec![0, 2, 2, 5]);
+        assert_eq!(prefix_sums(&[426]), vec![0]);
+        assert_eq!(prefix_sums(&[]), vec![]);
+    }
+
+    #[test]
+    fn insertion_sort_instrumented_test() {
+        println!("Asserting that the instrumented insertion sort sorts like insertion_sort.");
+
+        let mut a = vec![426, 3, 17, 3, 0, 99, 1];
+        let mut b = a.clone();
+        insertion_sort(&mut a);
+        insertion_sort_instrumented(&mut b[..]);
+        assert_eq!(a, b);
+
+        println!("Asserting that a reverse-sorted input of length n needs n(n-1)/2 comparisons and moves.");
+
+        let n = 100;
+        let mut reversed = (0..n).rev().collect::<Vec<u32>>();
+        let stats = insertion_sort_instrumented(&mut reversed);
+        assert_eq!(reversed, (0..n).collect::<Vec<u32>>());
+        assert_eq!(stats, SortStats { comparisons: 4950, moves: 4950 });
+
+        println!("Asserting that a sorted input needs n-1 comparisons and no moves.");
+
+        let mut sorted = (0..n).collect::<Vec<u32>>();
+        assert_eq!(insertion_sort_instrumented(&mut sorted), SortStats { comparisons: 99, moves: 0 });
+    }
+
+    #[test]
+    fn argsort_test() {
+        println!("Computing the sorting permutation of a vector with duplicates.");
+
+        assert_eq!(argsort(&[30, 10, 20, 10]), vec![1, 3, 2, 0]);
+        assert_eq!(argsort::<u32>(&[]), vec![]);
+    }
+
+    #[test]
+    fn sort_together_test() {
+        println!("Sorting names by their keys.");
+
+        assert_eq!(
+            sort_together(&[3, 1, 2], &["c", "a", "b"]),
+            Ok((vec![1, 2, 3], vec!["a", "b", "c"]))
+        );
+
+        println!("Asserting that slices of different lengths are rejected.");
+
+        assert_eq!(sort_together(&[3, 1], &["c", "a", "b"]), Err(SortingInstanceError));
+    }
+
+    #[test]
+    fn binary_search_test() {
+        let sorted = vec![1, 3, 3, 3, 7, 426];
+
+        println!("Searching elements that are contained in the slice.");
+        assert_eq!(binary_search(&sorted, &1), Ok(0));
+        assert_eq!(binary_search(&sorted, &7), Ok(4));
+        assert_eq!(binary_search(&sorted, &426), Ok(5));
+
+        println!("Asserting that the first occurrence of a repeated element is found.");
+        assert_eq!(binary_search(&sorted, &3), Ok(1));
+
+        println!("Computing insertion points of missing elements.");
+        assert_eq!(binary_search(&sorted, &0), Err(0));
+        assert_eq!(binary_search(&sorted, &5), Err(4));
+        assert_eq!(binary_search(&sorted, &1000), Err(6));
+
+        println!("Searching in the empty slice.");
+        assert_eq!(binary_search(&Vec::<u32>::new(), &426), Err(0));
+
+        println!("Asserting that the results agree with the standard library.");
+        for target in 0..430 {
+            assert_eq!(binary_search(&sorted, &target).is_ok(), sorted.binary_search(&target).is_ok());
+            if let Err(i) = binary_search(&sorted, &target) {
+                assert_eq!(sorted.binary_search(&target), Err(i));
+            }
+        }
+    }
 }
\ No newline at end of file
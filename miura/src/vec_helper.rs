@@ -1,10 +1,10 @@
-//! Contains helper functions that operate on integer vectors.
+//! Contains helper functions that operate on integer and floating point vectors.
 
 
 
 use crate::poly::Modulus;
 use std::collections::HashSet;
-use std::ops::Mul; // for trait bound for scale vector method
+use std::ops::{Mul, MulAssign}; // for trait bounds for scale vector methods
 
 
 /// Removes the trailing zeros/ multiples of the passed modulus from the passed vector,
@@ -13,13 +13,14 @@ use std::ops::Mul; // for trait bound for scale vector method
 pub fn remove_trailing_zeros(vec: &mut Vec<i32>, modulus: Modulus) {
     let mut n = vec.len();
     while 
-        vec.len() > 0 && // stop if vector empty (this means the vector models the zero polynomial)
-        (modulus == Modulus::None && vec[n-1] == 0 ) // remove trailing zeros for integer polynomial
-        || { // all multiples of the modulus are 0 in a remainder class ring
-            if let Modulus::Some(x) = modulus {
-                vec[n-1] % x == 0
-            } else { false }
-        }    
+        !vec.is_empty() && ( // stop if vector empty (this means the vector models the zero polynomial)
+            (modulus == Modulus::None && vec[n-1] == 0 ) // remove trailing zeros for integer polynomial
+            || { // all multiples of the modulus are 0 in a remainder class ring
+                if let Modulus::Some(x) = modulus {
+                    vec[n-1] % x == 0
+                } else { false }
+            }
+        )
     {
         vec.pop();
         n = vec.len(); // coefficients vector was shortened by 1
@@ -27,6 +28,17 @@ pub fn remove_trailing_zeros(vec: &mut Vec<i32>, modulus: Modulus) {
 }
 
 
+/// Removes the trailing entries with absolute value below the passed epsilon from the passed vector,
+/// e.g. vec![1.0, 2.0, 1e-12] with epsilon 1e-9 becomes vec![1.0, 2.0].
+/// This is the floating point analogue of remove_trailing_zeros 
+/// where tiny entries are treated as zero since they are usually rounding errors.
+pub fn trim_near_zero(vec: &mut Vec<f64>, epsilon: f64) {
+    while !vec.is_empty() && vec[vec.len()-1].abs() < epsilon {
+        vec.pop();
+    }
+}
+
+
 /// Shifts a vector by adding the passed number of zeros at the beginning
 /// i.e. vec![1, 1, 426] becomes vec![0, 0, 1, 1, 426] when shifted by 2.
 pub fn shift_vector(vec: &Vec<i32>, amt: usize) -> Vec<i32> {
@@ -53,10 +65,37 @@ pub fn scale_vector<T>(vec: &Vec<T>, amt: T) -> Vec<T>
         vec.iter().map(|x| amt * *x).collect::<Vec<T>>()
 }
 
+/// Scales the passed vector by the passed factor amt in place,
+/// i.e. vec![3, 2, 1] becomes vec![6, 4, 2] when scaled with 2.
+///
+/// Unlike scale_vector, no new vector is allocated.
+pub fn scale_vector_into<T>(vec: &mut [T], amt: T) 
+    where
+        T: MulAssign + Copy
+{
+    for x in vec.iter_mut() {
+        *x *= amt;
+    }
+}
+
+/// Applies the passed function f to every contiguous window of the passed size in vec
+/// and returns the results in the order of the windows,
+/// e.g. for computing differences or moving averages.
+///
+/// If size is 0 or greater than the length of vec, the result is empty.
+pub fn windows_map<T, R>(vec: &[T], size: usize, f: impl Fn(&[T]) -> R) -> Vec<R> {
+    // windows of size 0 are not meaningful (and rejected by the standard library)
+    if size == 0 {
+        return vec![];
+    }
+
+    vec.windows(size).map(f).collect()
+}
+
 /// Iterate over vector and assure that
 /// (i)   all numbers occur at most once
 /// (ii)  all occuring numbers are in {1, ..., n}
-pub fn check_unique_in_1_to_n(vec: &Vec<usize>, n: usize) -> bool {
+pub fn check_unique_in_1_to_n(vec: &[usize], n: usize) -> bool {
     /*
     * For the first requirement, store all numbers seen so far in a hash set
     * and check the set for containment of encountered number.
@@ -84,12 +123,55 @@ pub fn check_unique_in_1_to_n(vec: &Vec<usize>, n: usize) -> bool {
 
 /// Determines whether the passed vector of floating point numbers is the zero vector.
 pub fn is_zero_vector(vec: Vec<f32>) -> bool {
-    for k in vec {
-        if k != 0.0 {
-            return false;
-        }
+    is_zero_slice(&vec)
+}
+
+/* 
+ * Borrowing variant of is_zero_vector 
+ * that stops at the first non-zero entry and does not need an owned vector.
+ */
+pub(crate) fn is_zero_slice(vec: &[f32]) -> bool {
+    vec.iter().all(|&k| k == 0.0)
+}
+
+/// Computes the dot product of the two passed vectors of floating point numbers.
+///
+/// If the vectors do not have the same length, an error variant is returned.
+pub fn dot_product(a: &[f32], b: &[f32]) -> Result<f32, VecError> {
+    if a.len() != b.len() {
+        return Err(VecError::LengthMismatchError);
     }
-    true
+
+    Ok(a.iter().zip(b.iter()).map(|(x, y)| x * y).sum())
+}
+
+/// Computes the mean of the passed values weighted by the passed weights,
+/// i.e. the dot product of values and weights divided by the total weight.
+///
+/// If values and weights do not have the same length or the total weight is 0,
+/// an error variant is returned.
+pub fn weighted_mean(values: &[f32], weights: &[f32]) -> Result<f32, VecError> {
+    let weighted_sum = dot_product(values, weights)?;
+    let total_weight: f32 = weights.iter().sum();
+
+    if total_weight == 0.0 {
+        return Err(VecError::ZeroTotalWeightError);
+    }
+
+    Ok(weighted_sum / total_weight)
+}
+
+
+
+/// Type modelling all different kinds of errors
+/// that can occur when working with vectors.
+#[derive(PartialEq, Debug, Clone)]
+pub enum VecError {
+    /// Returned when two vectors that are supposed to be combined entry-wise
+    /// have different lengths.
+    LengthMismatchError,
+    /// Returned when attempting to compute a weighted mean with weights summing to 0.
+    ZeroTotalWeightError
 }
 
 
@@ -103,6 +185,56 @@ pub fn is_zero_vector(vec: Vec<f32>) -> bool {
 mod tests {
     use crate::vec_helper::*;
 
+    #[test]
+    fn test_remove_trailing_zeros() {
+        println!("Removing trailing zeros over the integers.");
+
+        let mut vec1 = vec![2, 3, 0, 0];
+        remove_trailing_zeros(&mut vec1, Modulus::None);
+        assert_eq!(vec1, vec![2, 3]);
+
+        println!("Removing trailing multiples of the modulus.");
+
+        let mut vec2 = vec![2, 4, 5, 10];
+        remove_trailing_zeros(&mut vec2, Modulus::Some(5));
+        assert_eq!(vec2, vec![2, 4]);
+
+        println!("Removing trailing multiples of the modulus until the vector is empty.");
+
+        let mut vec3 = vec![5, 0];
+        remove_trailing_zeros(&mut vec3, Modulus::Some(5));
+        assert_eq!(vec3, vec![]);
+
+        println!("Asserting that empty vectors stay empty, for every modulus.");
+
+        let mut vec4: Vec<i32> = vec![];
+        remove_trailing_zeros(&mut vec4, Modulus::Some(5));
+        assert_eq!(vec4, vec![]);
+        remove_trailing_zeros(&mut vec4, Modulus::None);
+        assert_eq!(vec4, vec![]);
+    }
+
+    #[test]
+    fn test_trim_near_zero() {
+        println!("Removing a tiny trailing entry.");
+
+        let mut vec1 = vec![1.0, 2.0, 1e-12];
+        trim_near_zero(&mut vec1, 1e-9);
+        assert_eq!(vec1, vec![1.0, 2.0]);
+
+        println!("Asserting that entries above epsilon and non-trailing tiny entries are kept.");
+
+        let mut vec2 = vec![1e-12, 426.0, -1e-3];
+        trim_near_zero(&mut vec2, 1e-9);
+        assert_eq!(vec2, vec![1e-12, 426.0, -1e-3]);
+
+        println!("Trimming until the vector is empty.");
+
+        let mut vec3 = vec![0.0, -1e-10];
+        trim_near_zero(&mut vec3, 1e-9);
+        assert_eq!(vec3, Vec::<f64>::new());
+    }
+
     #[test]
     fn test_shift_vector() {
         println!("Shift vector by positive number.");
@@ -137,6 +269,35 @@ mod tests {
 
         let scaled_vector3 = scale_vector(&vec, 0);
         assert_eq!(scaled_vector3, vec![0, 0, 0]);
+
+        println!("Scale floating point vector.");
+
+        let float_vec = vec![426.0, 0.5, -1.0];
+        let scaled_vector4 = scale_vector(&float_vec, 2.0);
+        assert_eq!(scaled_vector4, vec![852.0, 1.0, -2.0]);
+    }
+
+    #[test]
+    fn test_scale_vector_into() {
+        println!("Scale integer vector in place.");
+
+        let mut vec = vec![426, 1, -1];
+        let (ptr, capacity) = (vec.as_ptr(), vec.capacity());
+
+        scale_vector_into(&mut vec, 5);
+        assert_eq!(vec, vec![2130, 5, -5]);
+
+        // the vector still uses the same buffer, i.e. nothing was reallocated
+        assert_eq!((vec.as_ptr(), vec.capacity()), (ptr, capacity));
+
+        println!("Scale floating point vector in place.");
+
+        let mut float_vec: Vec<f32> = vec![426.0, 0.5, -1.0];
+        let (float_ptr, float_capacity) = (float_vec.as_ptr(), float_vec.capacity());
+
+        scale_vector_into(&mut float_vec, 1.5);
+        assert_eq!(float_vec, vec![639.0, 0.75, -1.5]);
+        assert_eq!((float_vec.as_ptr(), float_vec.capacity()), (float_ptr, float_capacity));
     }
 
     #[test]
@@ -148,5 +309,55 @@ mod tests {
         println!("Testing non-zero vector.");
 
         assert_eq!(is_zero_vector(vec![426.0, 426.0]), false);
+
+        println!("Testing the borrowing variant, including the empty vector.");
+
+        assert!(is_zero_slice(&[0.0, -0.0]));
+        assert!(!is_zero_slice(&[0.0, 426.0, 0.0]));
+        assert!(is_zero_slice(&[]));
+    }
+
+    #[test]
+    fn test_windows_map() {
+        println!("Computing pairwise differences with windows of size 2.");
+
+        assert_eq!(windows_map(&[1, 3, 6, 10], 2, |w| w[1] - w[0]), vec![2, 3, 4]);
+
+        println!("Computing moving sums with windows of size 3.");
+
+        assert_eq!(windows_map(&[1, 3, 6, 10], 3, |w| w.iter().sum::<i32>()), vec![10, 19]);
+
+        println!("Asserting that too large and empty windows yield an empty result.");
+
+        assert_eq!(windows_map(&[1, 3, 6, 10], 5, |w| w[0]), Vec::<i32>::new());
+        assert_eq!(windows_map(&[1, 3, 6, 10], 0, |w| w.len()), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_dot_product() {
+        println!("Computing the dot product of two vectors.");
+
+        assert_eq!(dot_product(&[1.0, 2.0, 3.0], &[4.0, -5.0, 6.0]), Ok(12.0));
+        assert_eq!(dot_product(&[], &[]), Ok(0.0));
+
+        println!("Asserting that vectors of different lengths are rejected.");
+
+        assert_eq!(dot_product(&[1.0, 2.0], &[426.0]), Err(VecError::LengthMismatchError));
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        println!("Asserting that uniform weights yield the arithmetic mean.");
+
+        assert_eq!(weighted_mean(&[1.0, 2.0, 6.0], &[0.5, 0.5, 0.5]), Ok(3.0));
+
+        println!("Computing a weighted mean with non-uniform weights: (1*1 + 2*3 + 6*4)/8 = 31/8.");
+
+        assert_eq!(weighted_mean(&[1.0, 2.0, 6.0], &[1.0, 3.0, 4.0]), Ok(3.875));
+
+        println!("Asserting that length mismatches and zero total weight are rejected.");
+
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), Err(VecError::LengthMismatchError));
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]), Err(VecError::ZeroTotalWeightError));
     }
 }
\ No newline at end of file